@@ -0,0 +1,53 @@
+mod stationary;
+mod wild;
+
+use super::gen2::{DivParams, Gen2Dvs, Gen2PokeFilter, HiddenPower, Poke, SpecialTrait};
+use crate::rng::gameboy::{Div, GameboyRng};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen1Spread {
+    pub state: u16,
+    pub advance: usize,
+    pub dvs: Gen2Dvs,
+    // Gen 1 has no shinies, but Gen 2 derives shininess from the transferred DVs
+    pub shiny: bool,
+    pub max_dv: bool,
+    pub hidden_power: HiddenPower,
+}
+
+fn gen1_rng(config: &DivParams) -> GameboyRng {
+    let add_div = Div::new(config.adiv_index, config.adiv);
+    let sub_div = Div::new(config.sdiv_index, config.sdiv);
+    GameboyRng::new(config.state, add_div, sub_div)
+}
+
+// Gen 1 rolls DVs from the add byte of two consecutive rands
+fn generate_gen1_dvs(
+    rng: &mut GameboyRng,
+    state: u16,
+    advance: usize,
+    filter: Option<Gen2PokeFilter>,
+) -> Option<Gen1Spread> {
+    let [atkdef, _] = rng.next();
+    let [spespc, _] = rng.next();
+    let poke = Poke::new(atkdef, spespc);
+    let special_trait = poke.special_trait();
+
+    if let Some(filter) = filter {
+        if filter != special_trait {
+            return None;
+        }
+    }
+
+    Some(Gen1Spread {
+        state,
+        advance,
+        dvs: Gen2Dvs::from(&poke),
+        shiny: special_trait == SpecialTrait::Shiny,
+        max_dv: special_trait == SpecialTrait::MaxDv,
+        hidden_power: poke.hidden_power(),
+    })
+}
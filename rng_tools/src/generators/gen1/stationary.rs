@@ -0,0 +1,100 @@
+use super::{Gen1Spread, gen1_rng, generate_gen1_dvs};
+use crate::generators::gen2::{DivParams, Gen2PokeFilter};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen1StationaryOptions {
+    pub start_advance: usize,
+    pub end_advance: usize,
+    // Rands consumed between the target advance and the DV rolls
+    pub dv_delay: usize,
+    pub filter: Option<Gen2PokeFilter>,
+}
+
+// Gift Pokémon and static battles both roll DVs with two consecutive rands
+#[wasm_bindgen]
+pub fn gen1_generate_stationary(
+    config: DivParams,
+    opts: &Gen1StationaryOptions,
+) -> Vec<Gen1Spread> {
+    let mut rng = gen1_rng(&config);
+    let mut spreads = Vec::new();
+    for advance in opts.start_advance..=opts.end_advance {
+        let mut poke_rng = rng.clone();
+        for _ in 0..opts.dv_delay {
+            poke_rng.next();
+        }
+
+        if let Some(spread) = generate_gen1_dvs(&mut poke_rng, rng.state(), advance, opts.filter) {
+            spreads.push(spread);
+        }
+        rng.next();
+    }
+
+    spreads
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generators::gen2::Poke;
+    use crate::rng::gameboy::{Div, GameboyRng};
+
+    const CONFIG: DivParams = DivParams {
+        adiv: 0x78,
+        sdiv: 0x78,
+        adiv_index: 468,
+        sdiv_index: 16139,
+        state: 0x9fe3,
+    };
+
+    #[test]
+    fn generates_stationary() {
+        let opts = Gen1StationaryOptions {
+            start_advance: 0,
+            end_advance: 10,
+            dv_delay: 2,
+            filter: None,
+        };
+        let results = gen1_generate_stationary(CONFIG, &opts);
+
+        let mut rng = GameboyRng::new(
+            CONFIG.state,
+            Div::new(CONFIG.adiv_index, CONFIG.adiv),
+            Div::new(CONFIG.sdiv_index, CONFIG.sdiv),
+        );
+        for _ in 0..5 {
+            rng.next();
+        }
+        let state = rng.state();
+        rng.next();
+        rng.next();
+        let [atkdef, _] = rng.next();
+        let [spespc, _] = rng.next();
+        let poke = Poke::new(atkdef, spespc);
+
+        assert_eq!(results.len(), 11);
+        assert_eq!(results[5].state, state);
+        assert_eq!(results[5].dvs.bytes(), [atkdef, spespc]);
+        assert_eq!(results[5].hidden_power, poke.hidden_power());
+    }
+
+    #[test]
+    fn filters_stationary() {
+        let opts = Gen1StationaryOptions {
+            start_advance: 0,
+            end_advance: 20000,
+            dv_delay: 0,
+            filter: Some(Gen2PokeFilter::Shiny),
+        };
+        let results = gen1_generate_stationary(CONFIG, &opts);
+
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|result| {
+            result.shiny && result.dvs.def == 10 && result.dvs.spe == 10 && result.dvs.spc == 10
+        }));
+    }
+}
@@ -0,0 +1,168 @@
+use super::{Gen1Spread, gen1_rng, generate_gen1_dvs};
+use crate::Species;
+use crate::generators::gen2::{DivParams, Gen2PokeFilter};
+use crate::rng::gameboy::GameboyRng;
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+// Grass and water share the same ten slot table
+const SLOT_THRESHOLDS: [u8; 10] = [50, 101, 140, 165, 190, 215, 228, 241, 252, 255];
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen1WildSlot {
+    pub species: Species,
+    pub level: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen1WildOptions {
+    pub start_advance: usize,
+    pub end_advance: usize,
+    // None when the encounter is forced, such as with fishing
+    pub encounter_rate: Option<u8>,
+    pub slots: Vec<Gen1WildSlot>,
+    pub dv_delay: usize,
+    pub slot_filter: Option<Vec<u8>>,
+    pub filter: Option<Gen2PokeFilter>,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen1WildSpread {
+    pub spread: Gen1Spread,
+    pub slot: u8,
+    pub species: Species,
+    pub level: u8,
+}
+
+// The encounter check and slot both read the rand from the frame's vblank
+fn generate_gen1_wild(
+    rng: &GameboyRng,
+    advance: usize,
+    opts: &Gen1WildOptions,
+) -> Option<Gen1WildSpread> {
+    let state = rng.state();
+    let [encounter_rand, slot_rand] = [rng.r_add, rng.r_sub];
+
+    if let Some(encounter_rate) = opts.encounter_rate {
+        if encounter_rand >= encounter_rate {
+            return None;
+        }
+    }
+
+    let slot = SLOT_THRESHOLDS
+        .iter()
+        .position(|&threshold| threshold >= slot_rand)
+        .unwrap_or_default() as u8;
+    if let Some(slot_filter) = &opts.slot_filter {
+        if !slot_filter.contains(&slot) {
+            return None;
+        }
+    }
+
+    let wild_slot = opts.slots.get(slot as usize)?;
+
+    let mut rng = rng.clone();
+    for _ in 0..opts.dv_delay {
+        rng.next();
+    }
+
+    let spread = generate_gen1_dvs(&mut rng, state, advance, opts.filter)?;
+
+    Some(Gen1WildSpread {
+        spread,
+        slot,
+        species: wild_slot.species,
+        level: wild_slot.level,
+    })
+}
+
+#[wasm_bindgen]
+pub fn gen1_generate_wild(config: DivParams, opts: &Gen1WildOptions) -> Vec<Gen1WildSpread> {
+    let mut rng = gen1_rng(&config);
+    let mut spreads = Vec::new();
+    for advance in opts.start_advance..=opts.end_advance {
+        if let Some(spread) = generate_gen1_wild(&rng, advance, opts) {
+            spreads.push(spread);
+        }
+        rng.next();
+    }
+
+    spreads
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CONFIG: DivParams = DivParams {
+        adiv: 0x78,
+        sdiv: 0x78,
+        adiv_index: 468,
+        sdiv_index: 16139,
+        state: 0x9fe3,
+    };
+
+    // Viridian Forest in Red and Blue
+    fn viridian_forest_slots() -> Vec<Gen1WildSlot> {
+        [
+            (Species::Weedle, 4),
+            (Species::Kakuna, 5),
+            (Species::Weedle, 3),
+            (Species::Weedle, 5),
+            (Species::Kakuna, 4),
+            (Species::Kakuna, 6),
+            (Species::Metapod, 4),
+            (Species::Caterpie, 3),
+            (Species::Pikachu, 3),
+            (Species::Pikachu, 5),
+        ]
+        .into_iter()
+        .map(|(species, level)| Gen1WildSlot { species, level })
+        .collect()
+    }
+
+    #[test]
+    fn generates_wild() {
+        let opts = Gen1WildOptions {
+            start_advance: 0,
+            end_advance: 500,
+            encounter_rate: Some(8),
+            slots: viridian_forest_slots(),
+            dv_delay: 0,
+            slot_filter: None,
+            filter: None,
+        };
+        let results = gen1_generate_wild(CONFIG, &opts);
+
+        assert!(!results.is_empty());
+        results.iter().for_each(|result| {
+            let slot = &opts.slots[result.slot as usize];
+            assert_eq!(result.species, slot.species);
+            assert_eq!(result.level, slot.level);
+        });
+    }
+
+    #[test]
+    fn filters_wild() {
+        let opts = Gen1WildOptions {
+            start_advance: 0,
+            end_advance: 50000,
+            encounter_rate: None,
+            slots: viridian_forest_slots(),
+            dv_delay: 1,
+            slot_filter: Some(vec![0, 2, 3]),
+            filter: Some(Gen2PokeFilter::Shiny),
+        };
+        let results = gen1_generate_wild(CONFIG, &opts);
+
+        assert!(!results.is_empty());
+        results.iter().for_each(|result| {
+            assert!(result.spread.shiny);
+            assert_eq!(result.species, Species::Weedle);
+        });
+    }
+}
@@ -1,12 +1,19 @@
 use super::poke::{Poke, SpecialTrait, possible_special_trait};
-use super::{DivParams, Gen2PokeFilter, Gen2Spread};
+use super::{DivParams, Gen2DivVariant, Gen2PokeFilter, Gen2Spread};
 use crate::rng::gameboy::{Div, GameboyRng, Offset};
 use wasm_bindgen::prelude::*;
 
+// Rands during the menu delay before Celebi's own rands
+const CELEBI_MENU_RANDS: usize = 595;
+
+// The menu delay, the div offset rand, at most 3 extra rands, the div increase rand,
+// 12 more rands, the 0x6f div increase rand, then both DV rands
+pub const CELEBI_CONSUMED_RANDS: usize = CELEBI_MENU_RANDS + 1 + 3 + 1 + 12 + 1 + 2;
+
 fn generate_celebi_rands(rng: &GameboyRng, extra_consumed_rands: u8, div_off: u8) -> [[u8; 2]; 2] {
     let mut rng = rng.clone();
 
-    for _ in 0..595 {
+    for _ in 0..CELEBI_MENU_RANDS {
         rng.next();
     }
 
@@ -40,15 +47,18 @@ fn generate_celebi_rands(rng: &GameboyRng, extra_consumed_rands: u8, div_off: u8
     [poke_rand_1, poke_rand_2]
 }
 
-fn potential_celebis(rng: &GameboyRng) -> Vec<Poke> {
+pub fn potential_celebis(rng: &GameboyRng) -> Vec<(Gen2DivVariant, Poke)> {
     let mut result = vec![];
 
     for extra_consumed_rands in [2, 3] {
         for div_off in [0xba, 0xbb] {
             let [[_, atkdef], [_, spespc]] =
                 generate_celebi_rands(rng, extra_consumed_rands, div_off);
-            let poke = Poke::new(atkdef, spespc);
-            result.push(poke);
+            let variant = Gen2DivVariant::Celebi {
+                extra_consumed_rands,
+                div_off,
+            };
+            result.push((variant, Poke::new(atkdef, spespc)));
         }
     }
 
@@ -57,7 +67,7 @@ fn potential_celebis(rng: &GameboyRng) -> Vec<Poke> {
 
 fn has_potential_special_celebi(rng: &GameboyRng) -> SpecialTrait {
     let pokes = potential_celebis(rng);
-    possible_special_trait(&pokes)
+    possible_special_trait(pokes.iter().map(|(_, poke)| poke))
 }
 
 #[wasm_bindgen]
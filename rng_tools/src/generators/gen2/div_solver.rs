@@ -0,0 +1,339 @@
+use super::dv_searcher::{Gen2Dvs, Gen2Encounter, Gen2Observation};
+use super::poke::Poke;
+use super::{DivParams, Gen2Game};
+use crate::rng::gameboy::{Div, GameboyRng};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen2ObservedPoke {
+    pub advance: usize,
+    pub encounter: Gen2Encounter,
+    pub observation: Gen2Observation,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen2DivSolverOptions {
+    pub game: Gen2Game,
+    pub states: Vec<u16>,
+    pub pokes: Vec<Gen2ObservedPoke>,
+}
+
+impl From<&GameboyRng> for DivParams {
+    fn from(rng: &GameboyRng) -> Self {
+        Self {
+            adiv: rng.add_div.value(),
+            sdiv: rng.sub_div.value(),
+            adiv_index: rng.add_div.index(),
+            sdiv_index: rng.sub_div.index(),
+            state: rng.state(),
+        }
+    }
+}
+
+fn generates_poke(game: Gen2Game, rng: &GameboyRng, observed: &Gen2ObservedPoke) -> bool {
+    let mut rng = rng.clone();
+    for _ in 0..observed.advance {
+        rng.next();
+    }
+
+    observed
+        .encounter
+        .potential_pokes(game, &rng)
+        .iter()
+        .any(|(_, poke)| observed.observation.matches(poke))
+}
+
+// The DV bytes every variant rolls for each observed Pokémon
+fn rolled_bytes(game: Gen2Game, rng: &GameboyRng, pokes: &[Gen2ObservedPoke]) -> Vec<Vec<[u8; 2]>> {
+    pokes
+        .iter()
+        .map(|observed| {
+            let mut rng = rng.clone();
+            for _ in 0..observed.advance {
+                rng.next();
+            }
+
+            observed
+                .encounter
+                .potential_pokes(game, &rng)
+                .iter()
+                .map(|(_, poke)| Gen2Dvs::from(poke).bytes())
+                .collect()
+        })
+        .collect()
+}
+
+fn bytes_key([atkdef, spespc]: [u8; 2]) -> usize {
+    ((atkdef as usize) << 8) | spespc as usize
+}
+
+// DV bytes are rolled from the sub byte, which the add side only reaches through its carries.
+// That makes each byte the sum of an add-DIV part and a sub-DIV part,
+// so each side is rolled against a reference DIV and the parts are joined on the observed bytes.
+fn solve_from_pokes(
+    game: Gen2Game,
+    state: u16,
+    pokes: &[Gen2ObservedPoke],
+    horizon: usize,
+) -> Vec<DivParams> {
+    if pokes.is_empty() {
+        return vec![];
+    }
+
+    let indexes = Div::candidate_indexes(horizon);
+    let reference = Div::new(indexes[0], 0);
+    let rolled = |add_div: &Div, sub_div: &Div| {
+        let rng = GameboyRng::new(state, add_div.clone(), sub_div.clone());
+        rolled_bytes(game, &rng, pokes)
+    };
+    let reference_bytes = rolled(&reference, &reference);
+
+    let mut add_parts = HashMap::<_, Vec<Div>>::new();
+    let mut sub_parts = HashMap::<_, Vec<Div>>::new();
+    for &index in indexes.iter() {
+        for value in 0..=u8::MAX {
+            let add_div = Div::new(index, value);
+            add_parts
+                .entry(rolled(&add_div, &reference))
+                .or_default()
+                .push(add_div);
+        }
+
+        // The sub DIV value is subtracted a fixed number of times, so its part steps evenly
+        let zero = rolled(&reference, &Div::new(index, 0));
+        let one = rolled(&reference, &Div::new(index, 1));
+        for value in 0..=u8::MAX {
+            let sub_part = zero
+                .iter()
+                .zip(one.iter())
+                .zip(reference_bytes.iter())
+                .map(|((zero, one), reference)| {
+                    zero.iter()
+                        .zip(one.iter())
+                        .zip(reference.iter())
+                        .map(|((zero, one), reference)| {
+                            [0, 1].map(|byte| {
+                                let step = one[byte].wrapping_sub(zero[byte]);
+                                zero[byte]
+                                    .wrapping_add(step.wrapping_mul(value))
+                                    .wrapping_sub(reference[byte])
+                            })
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            sub_parts
+                .entry(sub_part)
+                .or_default()
+                .push(Div::new(index, value));
+        }
+    }
+
+    let observed_keys = pokes
+        .iter()
+        .map(|observed| {
+            (0..=u16::MAX)
+                .map(|key| {
+                    let [atkdef, spespc] = key.to_be_bytes();
+                    observed.observation.matches(&Poke::new(atkdef, spespc))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let rolls_observed = |add_part: &[Vec<[u8; 2]>], sub_part: &[Vec<[u8; 2]>], poke: usize| {
+        add_part[poke]
+            .iter()
+            .zip(sub_part[poke].iter())
+            .any(|(add_bytes, sub_bytes)| {
+                let atkdef = add_bytes[0].wrapping_add(sub_bytes[0]);
+                let spespc = add_bytes[1].wrapping_add(sub_bytes[1]);
+                observed_keys[poke][bytes_key([atkdef, spespc])]
+            })
+    };
+
+    let add_parts = add_parts.into_iter().collect::<Vec<_>>();
+    let sub_parts = sub_parts.into_iter().collect::<Vec<_>>();
+    let first_keys = (0..=u16::MAX)
+        .filter(|&key| observed_keys[0][key as usize])
+        .map(u16::to_be_bytes)
+        .collect::<Vec<_>>();
+    let variant_count = reference_bytes[0].len();
+
+    let mut matches = vec![];
+    for variant in 0..variant_count {
+        let mut adds_by_bytes = HashMap::<usize, Vec<usize>>::new();
+        for (add_idx, (add_part, _)) in add_parts.iter().enumerate() {
+            adds_by_bytes
+                .entry(bytes_key(add_part[0][variant]))
+                .or_default()
+                .push(add_idx);
+        }
+
+        for (sub_idx, (sub_part, _)) in sub_parts.iter().enumerate() {
+            let sub_bytes = sub_part[0][variant];
+            for &[atkdef, spespc] in first_keys.iter() {
+                let add_bytes = [
+                    atkdef.wrapping_sub(sub_bytes[0]),
+                    spespc.wrapping_sub(sub_bytes[1]),
+                ];
+                let Some(add_idxs) = adds_by_bytes.get(&bytes_key(add_bytes)) else {
+                    continue;
+                };
+                matches.extend(add_idxs.iter().map(|&add_idx| (add_idx, sub_idx)));
+            }
+        }
+    }
+    matches.sort_unstable();
+    matches.dedup();
+
+    matches
+        .into_iter()
+        .filter(|&(add_idx, sub_idx)| {
+            (1..pokes.len())
+                .all(|poke| rolls_observed(&add_parts[add_idx].0, &sub_parts[sub_idx].0, poke))
+        })
+        .flat_map(|(add_idx, sub_idx)| {
+            let sub_divs = &sub_parts[sub_idx].1;
+            add_parts[add_idx].1.iter().flat_map(move |add_div| {
+                sub_divs.iter().map(move |sub_div| {
+                    DivParams::from(&GameboyRng::new(state, add_div.clone(), sub_div.clone()))
+                })
+            })
+        })
+        .collect()
+}
+
+// Without two observed states there are no DIVs to read off the rands,
+// so the DIVs are searched from the Pokémon alone, starting from the given state.
+// A reset clears the rng state to 0, which is used when no state is given.
+#[wasm_bindgen]
+pub fn gen2_solve_div_params(opts: &Gen2DivSolverOptions) -> Vec<DivParams> {
+    let horizon = opts
+        .pokes
+        .iter()
+        .map(|poke| poke.advance + poke.encounter.consumed_rands())
+        .max()
+        .unwrap_or_default();
+
+    if opts.states.len() < 2 {
+        let state = opts.states.first().copied().unwrap_or_default();
+        return solve_from_pokes(opts.game, state, &opts.pokes, horizon);
+    }
+
+    GameboyRng::solve(&opts.states, horizon)
+        .iter()
+        .filter(|rng| {
+            opts.pokes
+                .iter()
+                .all(|poke| generates_poke(opts.game, rng, poke))
+        })
+        .map(DivParams::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn params_rng(params: &DivParams) -> GameboyRng {
+        GameboyRng::new(
+            params.state,
+            Div::new(params.adiv_index, params.adiv),
+            Div::new(params.sdiv_index, params.sdiv),
+        )
+    }
+
+    #[test]
+    fn solves_from_rands_and_starter() {
+        let adiv = Div::new(468, 0x78);
+        let sdiv = Div::new(16139, 0x78);
+        let mut rng = GameboyRng::new(0x9fe3, adiv, sdiv);
+        let initial_rng = rng.clone();
+        let states = [rng.state()]
+            .into_iter()
+            .chain((0..24).map(|_| rng.next_u16()))
+            .collect::<Vec<_>>();
+
+        let mut starter_rng = initial_rng.clone();
+        for _ in 0..60 {
+            starter_rng.next();
+        }
+        let (_, starter) =
+            Gen2Encounter::Starter.potential_pokes(Gen2Game::Crystal, &starter_rng)[40];
+        let observed = Gen2ObservedPoke {
+            advance: 60,
+            encounter: Gen2Encounter::Starter,
+            observation: Gen2Observation::Dvs {
+                atk: starter.atk,
+                def: starter.def,
+                spe: starter.spe,
+                spc: starter.spc,
+            },
+        };
+
+        let results = gen2_solve_div_params(&Gen2DivSolverOptions {
+            game: Gen2Game::Crystal,
+            states,
+            pokes: vec![observed.clone()],
+        });
+
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|params| params.state == 0x9fe3));
+        assert!(
+            results.iter().all(|params| {
+                generates_poke(Gen2Game::Crystal, &params_rng(params), &observed)
+            })
+        );
+    }
+
+    #[test]
+    fn solves_from_starters_after_a_reset() {
+        let rng = GameboyRng::new(0, Div::new(468, 0x78), Div::new(16139, 0x9a));
+        let pokes = [(2, 40), (5, 12), (9, 70)]
+            .into_iter()
+            .map(|(advance, variant)| {
+                let mut starter_rng = rng.clone();
+                for _ in 0..advance {
+                    starter_rng.next();
+                }
+                let (_, starter) = Gen2Encounter::Starter
+                    .potential_pokes(Gen2Game::Crystal, &starter_rng)[variant];
+                Gen2ObservedPoke {
+                    advance,
+                    encounter: Gen2Encounter::Starter,
+                    observation: Gen2Observation::Dvs {
+                        atk: starter.atk,
+                        def: starter.def,
+                        spe: starter.spe,
+                        spc: starter.spc,
+                    },
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let results = gen2_solve_div_params(&Gen2DivSolverOptions {
+            game: Gen2Game::Crystal,
+            states: vec![],
+            pokes: pokes.clone(),
+        });
+
+        let rands = |mut rng: GameboyRng| (0..24).map(|_| rng.next_u16()).collect::<Vec<_>>();
+        let expected = rands(rng.clone());
+        assert!(
+            results
+                .iter()
+                .any(|params| params.state == 0 && rands(params_rng(params)) == expected)
+        );
+        assert!(results.iter().all(|params| {
+            let rng = params_rng(params);
+            pokes
+                .iter()
+                .all(|poke| generates_poke(Gen2Game::Crystal, &rng, poke))
+        }));
+    }
+}
@@ -0,0 +1,288 @@
+use super::celebi::{CELEBI_CONSUMED_RANDS, potential_celebis};
+use super::poke::Poke;
+use super::starter::potential_starters;
+use super::{DivParams, Gen2DivVariant, Gen2Game};
+use crate::rng::gameboy::{Div, GameboyRng};
+use crate::{NatureFactor, StatsValue, calculate_hp, calculate_non_hp};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Gen2Encounter {
+    Starter,
+    Celebi,
+}
+
+impl Gen2Encounter {
+    pub fn potential_pokes(&self, game: Gen2Game, rng: &GameboyRng) -> Vec<(Gen2DivVariant, Poke)> {
+        match self {
+            Gen2Encounter::Starter => potential_starters(game, rng),
+            Gen2Encounter::Celebi if game.has_celebi_event() => potential_celebis(rng),
+            Gen2Encounter::Celebi => vec![],
+        }
+    }
+
+    // Upper bound of rands consumed after the target advance, including the Celebi menu delay
+    pub fn consumed_rands(&self) -> usize {
+        match self {
+            Gen2Encounter::Starter => 2,
+            Gen2Encounter::Celebi => CELEBI_CONSUMED_RANDS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen2Dvs {
+    pub hp: u8,
+    pub atk: u8,
+    pub def: u8,
+    pub spe: u8,
+    pub spc: u8,
+}
+
+impl Gen2Dvs {
+    pub fn bytes(&self) -> [u8; 2] {
+        [(self.atk << 4) | self.def, (self.spe << 4) | self.spc]
+    }
+}
+
+impl From<&Poke> for Gen2Dvs {
+    fn from(poke: &Poke) -> Self {
+        Self {
+            hp: poke.hp,
+            atk: poke.atk,
+            def: poke.def,
+            spe: poke.spe,
+            spc: poke.spc,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Gen2Observation {
+    Dvs {
+        atk: u8,
+        def: u8,
+        spe: u8,
+        spc: u8,
+    },
+    Stats {
+        base_stats: StatsValue,
+        level: u8,
+        stats: StatsValue,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen2DvSearchResult {
+    pub advance: usize,
+    pub state: u16,
+    pub variant: Gen2DivVariant,
+    pub dvs: Gen2Dvs,
+}
+
+// Gen 2 stats use twice the DV in place of the IV, and the special DV for both special stats
+pub fn gen2_stats(base_stats: &StatsValue, level: u8, poke: &Poke) -> StatsValue {
+    StatsValue {
+        hp: calculate_hp(base_stats.hp, poke.hp * 2, 0, level),
+        atk: calculate_non_hp(base_stats.atk, poke.atk * 2, 0, level, NatureFactor::Equal),
+        def: calculate_non_hp(base_stats.def, poke.def * 2, 0, level, NatureFactor::Equal),
+        spa: calculate_non_hp(base_stats.spa, poke.spc * 2, 0, level, NatureFactor::Equal),
+        spd: calculate_non_hp(base_stats.spd, poke.spc * 2, 0, level, NatureFactor::Equal),
+        spe: calculate_non_hp(base_stats.spe, poke.spe * 2, 0, level, NatureFactor::Equal),
+    }
+}
+
+impl Gen2Observation {
+    pub fn matches(&self, poke: &Poke) -> bool {
+        match self {
+            Gen2Observation::Dvs { atk, def, spe, spc } => {
+                poke.atk == *atk && poke.def == *def && poke.spe == *spe && poke.spc == *spc
+            }
+            Gen2Observation::Stats {
+                base_stats,
+                level,
+                stats,
+            } => gen2_stats(base_stats, *level, poke) == *stats,
+        }
+    }
+}
+
+#[wasm_bindgen]
+pub fn gen2_search_dvs(
+    game: Gen2Game,
+    config: DivParams,
+    start_advance: usize,
+    end_advance: usize,
+    encounter: Gen2Encounter,
+    observation: Gen2Observation,
+) -> Vec<Gen2DvSearchResult> {
+    let add_div = Div::new(config.adiv_index, config.adiv);
+    let sub_div = Div::new(config.sdiv_index, config.sdiv);
+    let mut rng = GameboyRng::new(config.state, add_div, sub_div);
+    let mut results = Vec::new();
+    for advance in start_advance..=end_advance {
+        results.extend(
+            encounter
+                .potential_pokes(game, &rng)
+                .into_iter()
+                .filter(|(_, poke)| observation.matches(poke))
+                .map(|(variant, poke)| Gen2DvSearchResult {
+                    advance,
+                    state: rng.state(),
+                    variant,
+                    dvs: Gen2Dvs::from(&poke),
+                }),
+        );
+        rng.next();
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CONFIG: DivParams = DivParams {
+        adiv: 0x78,
+        sdiv: 0x78,
+        adiv_index: 468,
+        sdiv_index: 16139,
+        state: 0x9fe3,
+    };
+
+    // Cyndaquil
+    const BASE_STATS: StatsValue = StatsValue {
+        hp: 39,
+        atk: 52,
+        def: 43,
+        spa: 60,
+        spd: 50,
+        spe: 65,
+    };
+
+    const CELEBI_BASE_STATS: StatsValue = StatsValue {
+        hp: 100,
+        atk: 100,
+        def: 100,
+        spa: 100,
+        spd: 100,
+        spe: 100,
+    };
+
+    #[test]
+    fn finds_observed_dvs() {
+        let add_div = Div::new(CONFIG.adiv_index, CONFIG.adiv);
+        let sub_div = Div::new(CONFIG.sdiv_index, CONFIG.sdiv);
+        let mut rng = GameboyRng::new(CONFIG.state, add_div, sub_div);
+        for _ in 0..20 {
+            rng.next();
+        }
+        let (variant, poke) = potential_starters(Gen2Game::Crystal, &rng)[0];
+
+        let results = gen2_search_dvs(
+            Gen2Game::Crystal,
+            CONFIG,
+            0,
+            40,
+            Gen2Encounter::Starter,
+            Gen2Observation::Dvs {
+                atk: poke.atk,
+                def: poke.def,
+                spe: poke.spe,
+                spc: poke.spc,
+            },
+        );
+
+        assert!(results.contains(&Gen2DvSearchResult {
+            advance: 20,
+            state: rng.state(),
+            variant,
+            dvs: Gen2Dvs::from(&poke),
+        }));
+        assert!(
+            results
+                .iter()
+                .all(|result| result.dvs == Gen2Dvs::from(&poke))
+        );
+    }
+
+    #[test]
+    fn finds_observed_stats() {
+        let poke = Poke::new(0xaa, 0xaa);
+        let stats = gen2_stats(&BASE_STATS, 5, &poke);
+        assert_eq!(
+            stats,
+            StatsValue {
+                hp: 18,
+                atk: 11,
+                def: 10,
+                spa: 12,
+                spd: 11,
+                spe: 12,
+            }
+        );
+
+        let add_div = Div::new(CONFIG.adiv_index, CONFIG.adiv);
+        let sub_div = Div::new(CONFIG.sdiv_index, CONFIG.sdiv);
+        let mut rng = GameboyRng::new(CONFIG.state, add_div, sub_div);
+        for _ in 0..100 {
+            rng.next();
+        }
+        let (variant, celebi) = potential_celebis(&rng)[0];
+
+        let observation = Gen2Observation::Stats {
+            base_stats: CELEBI_BASE_STATS,
+            level: 30,
+            stats: gen2_stats(&CELEBI_BASE_STATS, 30, &celebi),
+        };
+        let results = gen2_search_dvs(
+            Gen2Game::Crystal,
+            CONFIG,
+            0,
+            200,
+            Gen2Encounter::Celebi,
+            observation.clone(),
+        );
+
+        assert!(results.contains(&Gen2DvSearchResult {
+            advance: 100,
+            state: rng.state(),
+            variant,
+            dvs: Gen2Dvs::from(&celebi),
+        }));
+        assert!(results.iter().all(|result| {
+            let poke = Poke::new(
+                (result.dvs.atk << 4) | result.dvs.def,
+                (result.dvs.spe << 4) | result.dvs.spc,
+            );
+            observation.matches(&poke)
+        }));
+    }
+
+    #[test]
+    fn celebi_is_crystal_only() {
+        let observation = Gen2Observation::Dvs {
+            atk: 10,
+            def: 10,
+            spe: 10,
+            spc: 10,
+        };
+        let results = gen2_search_dvs(
+            Gen2Game::Gold,
+            CONFIG,
+            0,
+            200,
+            Gen2Encounter::Celebi,
+            observation,
+        );
+
+        assert!(results.is_empty());
+    }
+}
@@ -0,0 +1,287 @@
+use super::dv_searcher::Gen2Dvs;
+use super::poke::{Poke, SpecialTrait};
+use super::{DivParams, Gen2DvFilter, Gen2Spread};
+use crate::rng::gameboy::{Div, GameboyRng};
+use crate::{Gender, Species};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+// Cumulative chances out of 0xffff, from OddEggProbabilities
+const ODD_EGGS: [(Species, bool, u16); 14] = [
+    (Species::Pichu, false, 0x147a),
+    (Species::Pichu, true, 0x170a),
+    (Species::Cleffa, false, 0x3fff),
+    (Species::Cleffa, true, 0x47ad),
+    (Species::Igglybuff, false, 0x70a3),
+    (Species::Igglybuff, true, 0x7851),
+    (Species::Smoochum, false, 0x9c28),
+    (Species::Smoochum, true, 0xa147),
+    (Species::Magby, false, 0xbae0),
+    (Species::Magby, true, 0xbfff),
+    (Species::Elekid, false, 0xdeb7),
+    (Species::Elekid, true, 0xe3d6),
+    (Species::Tyrogue, false, 0xfd6f),
+    (Species::Tyrogue, true, 0xffff),
+];
+
+// Odd eggs have hardcoded DVs
+const ODD_EGG_DVS: [u8; 2] = [0x00, 0x00];
+const SHINY_ODD_EGG_DVS: [u8; 2] = [0x2a, 0xaa];
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen2EggSpread {
+    pub spread: Gen2Spread,
+    pub species: Species,
+    pub gender: Gender,
+    pub dvs: Gen2Dvs,
+}
+
+fn egg_spread(rng: &GameboyRng, advance: usize, species: Species, poke: &Poke) -> Gen2EggSpread {
+    let special_trait = poke.special_trait();
+    Gen2EggSpread {
+        spread: Gen2Spread {
+            state: rng.state(),
+            advance,
+            shiny: special_trait == SpecialTrait::Shiny,
+            max_dv: special_trait == SpecialTrait::MaxDv,
+        },
+        species,
+        gender: poke.gender(species.gender_ratio()),
+        dvs: Gen2Dvs::from(poke),
+    }
+}
+
+fn generate_odd_egg(rng: &GameboyRng) -> (Species, Poke) {
+    let mut rng = rng.clone();
+    // hRandomSub is compared to the high byte of each chance, and hRandomAdd to the low byte
+    let [r_add, r_sub] = rng.next();
+    let rand = u16::from_be_bytes([r_sub, r_add]);
+
+    let (species, shiny, _) = ODD_EGGS
+        .iter()
+        .find(|(_, _, chance)| rand <= *chance)
+        .unwrap_or(&ODD_EGGS[ODD_EGGS.len() - 1]);
+    let [atkdef, spespc] = if *shiny {
+        SHINY_ODD_EGG_DVS
+    } else {
+        ODD_EGG_DVS
+    };
+
+    (*species, Poke::new(atkdef, spespc))
+}
+
+#[wasm_bindgen]
+pub fn crystal_generate_odd_eggs(
+    config: DivParams,
+    start_advance: usize,
+    end_advance: usize,
+    filter: Gen2DvFilter,
+) -> Vec<Gen2EggSpread> {
+    let add_div = Div::new(config.adiv_index, config.adiv);
+    let sub_div = Div::new(config.sdiv_index, config.sdiv);
+    let mut rng = GameboyRng::new(config.state, add_div, sub_div);
+    let mut spreads = Vec::new();
+    for advance in start_advance..=end_advance {
+        let (species, poke) = generate_odd_egg(&rng);
+        if filter.pass(&poke, species.gender_ratio()) {
+            spreads.push(egg_spread(&rng, advance, species, &poke));
+        }
+        rng.next();
+    }
+
+    spreads
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Gen2DaycareParents {
+    Pair { mother: Gen2Dvs, father: Gen2Dvs },
+    Ditto { ditto: Gen2Dvs },
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen2DaycareOptions {
+    pub start_advance: usize,
+    pub end_advance: usize,
+    pub species: Species,
+    pub parents: Gen2DaycareParents,
+    pub filter: Gen2DvFilter,
+}
+
+fn generate_daycare_egg(rng: &GameboyRng, opts: &Gen2DaycareOptions) -> Poke {
+    let mut rng = rng.clone();
+    let [_, atkdef] = rng.next();
+    let [_, spespc] = rng.next();
+    let random_poke = Poke::new(atkdef, spespc);
+
+    // The gender comes from the random DVs, and the attack DV is never inherited.
+    // Ditto is checked before the gender, so genderless eggs still inherit from it.
+    let donor = match (
+        &opts.parents,
+        random_poke.gender(opts.species.gender_ratio()),
+    ) {
+        (Gen2DaycareParents::Ditto { ditto }, _) => ditto,
+        (_, Gender::Genderless) => return random_poke,
+        (Gen2DaycareParents::Pair { father, .. }, Gender::Female) => father,
+        (Gen2DaycareParents::Pair { mother, .. }, Gender::Male) => mother,
+    };
+
+    // Defense is inherited, and special keeps its lowest 3 bits
+    let [donor_atkdef, donor_spespc] = donor.bytes();
+    Poke::new(
+        (atkdef & 0xf0) | (donor_atkdef & 0xf),
+        (spespc & 0xf8) | (donor_spespc & 0x7),
+    )
+}
+
+#[wasm_bindgen]
+pub fn gen2_generate_daycare_eggs(
+    config: DivParams,
+    opts: &Gen2DaycareOptions,
+) -> Vec<Gen2EggSpread> {
+    let add_div = Div::new(config.adiv_index, config.adiv);
+    let sub_div = Div::new(config.sdiv_index, config.sdiv);
+    let mut rng = GameboyRng::new(config.state, add_div, sub_div);
+    let mut spreads = Vec::new();
+    for advance in opts.start_advance..=opts.end_advance {
+        let poke = generate_daycare_egg(&rng, opts);
+        if opts.filter.pass(&poke, opts.species.gender_ratio()) {
+            spreads.push(egg_spread(&rng, advance, opts.species, &poke));
+        }
+        rng.next();
+    }
+
+    spreads
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generators::gen2::Gen2PokeFilter;
+
+    const CONFIG: DivParams = DivParams {
+        adiv: 0x78,
+        sdiv: 0x78,
+        adiv_index: 468,
+        sdiv_index: 16139,
+        state: 0x9fe3,
+    };
+
+    const SHINY_DITTO: Gen2Dvs = Gen2Dvs {
+        hp: 0,
+        atk: 10,
+        def: 10,
+        spe: 10,
+        spc: 10,
+    };
+
+    #[test]
+    fn generates_odd_eggs() {
+        let results = crystal_generate_odd_eggs(CONFIG, 0, 1000, Gen2DvFilter::default());
+        assert_eq!(results.len(), 1001);
+
+        let shiny = results.iter().filter(|result| result.spread.shiny);
+        assert!(shiny.clone().count() > 0);
+        shiny.for_each(|result| {
+            assert_eq!(result.dvs.bytes(), SHINY_ODD_EGG_DVS);
+        });
+
+        let filter = Gen2DvFilter {
+            special_trait: Some(Gen2PokeFilter::Shiny),
+            gender: Some(Gender::Female),
+            hidden_power_type: None,
+        };
+        let filtered = crystal_generate_odd_eggs(CONFIG, 0, 1000, filter);
+        assert!(
+            filtered
+                .iter()
+                .all(|result| result.spread.shiny && result.gender == Gender::Female)
+        );
+    }
+
+    #[test]
+    fn compares_sub_byte_first() {
+        let results = crystal_generate_odd_eggs(CONFIG, 0, 9, Gen2DvFilter::default());
+
+        // hRandomAdd 0x74 and hRandomSub 0x0b, so 0x0b74 is under Pichu's 0x147a
+        assert_eq!(results[2].species, Species::Pichu);
+        assert!(!results[2].spread.shiny);
+        // hRandomAdd 0x3c and hRandomSub 0x3e
+        assert_eq!(results[9].species, Species::Cleffa);
+        assert!(!results[9].spread.shiny);
+    }
+
+    #[test]
+    fn inherits_from_ditto() {
+        let opts = Gen2DaycareOptions {
+            start_advance: 0,
+            end_advance: 2000,
+            species: Species::Chikorita,
+            parents: Gen2DaycareParents::Ditto { ditto: SHINY_DITTO },
+            filter: Gen2DvFilter::default(),
+        };
+        let results = gen2_generate_daycare_eggs(CONFIG, &opts);
+
+        results.iter().for_each(|result| {
+            assert_eq!(result.dvs.def, 10);
+            assert_eq!(result.dvs.spc & 0x7, 2);
+        });
+
+        let shiny = results.iter().filter(|result| result.spread.shiny).count();
+        assert!(shiny > 0);
+    }
+
+    #[test]
+    fn genderless_eggs_inherit_from_ditto() {
+        let opts = Gen2DaycareOptions {
+            start_advance: 0,
+            end_advance: 200,
+            species: Species::Magnemite,
+            parents: Gen2DaycareParents::Ditto { ditto: SHINY_DITTO },
+            filter: Gen2DvFilter::default(),
+        };
+        let results = gen2_generate_daycare_eggs(CONFIG, &opts);
+
+        assert_eq!(results.len(), 201);
+        results.iter().for_each(|result| {
+            assert_eq!(result.gender, Gender::Genderless);
+            assert_eq!(result.dvs.def, 10);
+            assert_eq!(result.dvs.spc & 0x7, 2);
+        });
+    }
+
+    #[test]
+    fn inherits_from_opposite_gender() {
+        let mother = Gen2Dvs {
+            hp: 0,
+            atk: 0,
+            def: 1,
+            spe: 0,
+            spc: 1,
+        };
+        let father = Gen2Dvs {
+            hp: 0,
+            atk: 0,
+            def: 2,
+            spe: 0,
+            spc: 2,
+        };
+        let opts = Gen2DaycareOptions {
+            start_advance: 0,
+            end_advance: 200,
+            species: Species::Cyndaquil,
+            parents: Gen2DaycareParents::Pair { mother, father },
+            filter: Gen2DvFilter::default(),
+        };
+
+        gen2_generate_daycare_eggs(CONFIG, &opts)
+            .iter()
+            .for_each(|result| match result.gender {
+                Gender::Female => assert_eq!(result.dvs.def, father.def),
+                _ => assert_eq!(result.dvs.def, mother.def),
+            });
+    }
+}
@@ -1,13 +1,67 @@
 mod celebi;
+mod div_solver;
+mod dv_searcher;
+mod egg;
 mod poke;
 mod researcher;
+mod roamer;
 mod starter;
+mod wild;
 
-use poke::SpecialTrait;
+use crate::{Gender, GenderRatio, Species};
+pub use dv_searcher::Gen2Dvs;
+pub use poke::{HiddenPower, HiddenPowerType, Poke, SpecialTrait};
 use serde::{Deserialize, Serialize};
 use tsify_next::Tsify;
 use wasm_bindgen::prelude::*;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Gen2Game {
+    Gold,
+    Silver,
+    Crystal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gen2StarterTiming {
+    // Rands consumed by the frame the A press is read on
+    pub a_press_rands: usize,
+    // The DV rolls happen mid-frame, after DIV has kept ticking through the give routine
+    pub div_scale: u8,
+    pub div_delay: u8,
+}
+
+const CRYSTAL_STARTER_TIMING: Gen2StarterTiming = Gen2StarterTiming {
+    a_press_rands: 1,
+    div_scale: 2,
+    div_delay: 10,
+};
+
+impl Gen2Game {
+    // Only Crystal's starter timing has been measured, so Gold and Silver starters aren't predicted
+    pub fn starter_timing(&self) -> Option<Gen2StarterTiming> {
+        match self {
+            Gen2Game::Crystal => Some(CRYSTAL_STARTER_TIMING),
+            Gen2Game::Gold | Gen2Game::Silver => None,
+        }
+    }
+
+    pub fn has_celebi_event(&self) -> bool {
+        *self == Gen2Game::Crystal
+    }
+
+    // Suicune is a static encounter in Crystal
+    pub fn roaming_beasts(&self) -> &'static [Species] {
+        match self {
+            Gen2Game::Gold | Gen2Game::Silver => {
+                &[Species::Raikou, Species::Entei, Species::Suicune]
+            }
+            Gen2Game::Crystal => &[Species::Raikou, Species::Entei],
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct Gen2Spread {
@@ -27,6 +81,21 @@ pub struct DivParams {
     pub state: u16,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Gen2DivVariant {
+    Starter {
+        a_div_1_offset: i8,
+        s_div_1_offset: i8,
+        a_div_2_offset: i8,
+        s_div_2_offset: i8,
+    },
+    Celebi {
+        extra_consumed_rands: u8,
+        div_off: u8,
+    },
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub enum Gen2PokeFilter {
@@ -46,3 +115,35 @@ impl PartialEq<SpecialTrait> for Gen2PokeFilter {
         )
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen2DvFilter {
+    pub special_trait: Option<Gen2PokeFilter>,
+    pub gender: Option<Gender>,
+    pub hidden_power_type: Option<HiddenPowerType>,
+}
+
+impl Gen2DvFilter {
+    pub fn pass(&self, poke: &Poke, gender_ratio: GenderRatio) -> bool {
+        if let Some(special_trait) = self.special_trait {
+            if special_trait != poke.special_trait() {
+                return false;
+            }
+        }
+
+        if let Some(gender) = self.gender {
+            if gender != poke.gender(gender_ratio) {
+                return false;
+            }
+        }
+
+        if let Some(hidden_power_type) = self.hidden_power_type {
+            if hidden_power_type != poke.hidden_power().hp_type {
+                return false;
+            }
+        }
+
+        true
+    }
+}
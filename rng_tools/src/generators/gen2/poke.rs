@@ -1,3 +1,7 @@
+use crate::{Gender, GenderRatio};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SpecialTrait {
     None,
@@ -5,9 +9,9 @@ pub enum SpecialTrait {
     MaxDv,
 }
 
-pub fn possible_special_trait(pokes: &[Poke]) -> SpecialTrait {
+pub fn possible_special_trait<'a>(pokes: impl IntoIterator<Item = &'a Poke>) -> SpecialTrait {
     pokes
-        .iter()
+        .into_iter()
         .filter_map(|poke| match poke.special_trait() {
             SpecialTrait::None => None,
             special_trait => Some(special_trait),
@@ -16,7 +20,56 @@ pub fn possible_special_trait(pokes: &[Poke]) -> SpecialTrait {
         .unwrap_or(SpecialTrait::None)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum HiddenPowerType {
+    Fighting,
+    Flying,
+    Poison,
+    Ground,
+    Rock,
+    Bug,
+    Ghost,
+    Steel,
+    Fire,
+    Water,
+    Grass,
+    Electric,
+    Psychic,
+    Ice,
+    Dragon,
+    Dark,
+}
+
+impl HiddenPowerType {
+    const ALL: [HiddenPowerType; 16] = [
+        HiddenPowerType::Fighting,
+        HiddenPowerType::Flying,
+        HiddenPowerType::Poison,
+        HiddenPowerType::Ground,
+        HiddenPowerType::Rock,
+        HiddenPowerType::Bug,
+        HiddenPowerType::Ghost,
+        HiddenPowerType::Steel,
+        HiddenPowerType::Fire,
+        HiddenPowerType::Water,
+        HiddenPowerType::Grass,
+        HiddenPowerType::Electric,
+        HiddenPowerType::Psychic,
+        HiddenPowerType::Ice,
+        HiddenPowerType::Dragon,
+        HiddenPowerType::Dark,
+    ];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct HiddenPower {
+    pub hp_type: HiddenPowerType,
+    pub power: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Poke {
     pub hp: u8,
     pub atk: u8,
@@ -53,6 +106,47 @@ impl Poke {
             && [0x2, 0x3, 0x6, 0x7, 0xA, 0xB, 0xE, 0xF].contains(&self.atk)
     }
 
+    // Gen 2 compares the attack DV against the species' female ratio
+    pub fn gender(&self, ratio: GenderRatio) -> Gender {
+        let female_atk_dvs = match ratio {
+            GenderRatio::Genderless => return Gender::Genderless,
+            GenderRatio::FemaleOnly => return Gender::Female,
+            GenderRatio::MaleOnly => return Gender::Male,
+            GenderRatio::OneToSeven => 2,
+            GenderRatio::OneToThree => 4,
+            GenderRatio::OneToOne => 8,
+            GenderRatio::ThreeToOne => 12,
+            GenderRatio::SevenToOne => 14,
+        };
+
+        if self.atk < female_atk_dvs {
+            Gender::Female
+        } else {
+            Gender::Male
+        }
+    }
+
+    // Bits 1 and 2 of each DV make a byte, which is split into 26 letters
+    pub fn unown_letter(&self) -> char {
+        let bits = ((self.atk & 6) << 5)
+            | ((self.def & 6) << 3)
+            | ((self.spe & 6) << 1)
+            | ((self.spc & 6) >> 1);
+        (b'A' + bits / 10) as char
+    }
+
+    // Type comes from the low attack and defense bits, power from the high bit of each DV
+    pub fn hidden_power(&self) -> HiddenPower {
+        let hp_type = HiddenPowerType::ALL[(((self.atk & 3) << 2) | (self.def & 3)) as usize];
+        let high_bits = ((self.atk >> 3) << 3)
+            | ((self.def >> 3) << 2)
+            | ((self.spe >> 3) << 1)
+            | (self.spc >> 3);
+        let power = ((5 * high_bits + (self.spc & 3)) / 2) + 31;
+
+        HiddenPower { hp_type, power }
+    }
+
     pub fn special_trait(&self) -> SpecialTrait {
         if self.is_shiny() {
             return SpecialTrait::Shiny;
@@ -65,3 +159,61 @@ impl Poke {
         SpecialTrait::None
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn calculates_hidden_power() {
+        assert_eq!(
+            Poke::new(0xff, 0xff).hidden_power(),
+            HiddenPower {
+                hp_type: HiddenPowerType::Dark,
+                power: 70,
+            }
+        );
+        // Shiny DVs always give Grass or Dragon
+        assert_eq!(
+            Poke::new(0xaa, 0xaa).hidden_power(),
+            HiddenPower {
+                hp_type: HiddenPowerType::Grass,
+                power: 69,
+            }
+        );
+        assert_eq!(
+            Poke::new(0x00, 0x00).hidden_power(),
+            HiddenPower {
+                hp_type: HiddenPowerType::Fighting,
+                power: 31,
+            }
+        );
+    }
+
+    #[test]
+    fn calculates_gender() {
+        assert_eq!(
+            Poke::new(0x1f, 0xff).gender(GenderRatio::OneToSeven),
+            Gender::Female
+        );
+        assert_eq!(
+            Poke::new(0x2f, 0x00).gender(GenderRatio::OneToSeven),
+            Gender::Male
+        );
+        assert_eq!(
+            Poke::new(0xdf, 0x00).gender(GenderRatio::SevenToOne),
+            Gender::Female
+        );
+        assert_eq!(
+            Poke::new(0x00, 0x00).gender(GenderRatio::Genderless),
+            Gender::Genderless
+        );
+    }
+
+    #[test]
+    fn calculates_unown_letter() {
+        assert_eq!(Poke::new(0x00, 0x00).unown_letter(), 'A');
+        assert_eq!(Poke::new(0xff, 0xff).unown_letter(), 'Z');
+        assert_eq!(Poke::new(0x22, 0x22).unown_letter(), 'I');
+    }
+}
@@ -0,0 +1,327 @@
+use super::dv_searcher::Gen2Dvs;
+use super::poke::{HiddenPower, Poke, SpecialTrait};
+use super::{DivParams, Gen2DvFilter, Gen2Game, Gen2Spread};
+use crate::rng::gameboy::{Div, GameboyRng};
+use crate::{GenderRatio, Species};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+// Routes 40 and 41 are water routes, so roamers never go there
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Gen2Route {
+    Route29,
+    Route30,
+    Route31,
+    Route32,
+    Route33,
+    Route34,
+    Route35,
+    Route36,
+    Route37,
+    Route38,
+    Route39,
+    Route42,
+    Route43,
+    Route44,
+    Route45,
+    Route46,
+}
+
+// Same order as RoamMaps, which is what a random jump indexes into
+const ROAM_ROUTES: [Gen2Route; 16] = [
+    Gen2Route::Route29,
+    Gen2Route::Route30,
+    Gen2Route::Route31,
+    Gen2Route::Route32,
+    Gen2Route::Route33,
+    Gen2Route::Route34,
+    Gen2Route::Route35,
+    Gen2Route::Route36,
+    Gen2Route::Route37,
+    Gen2Route::Route38,
+    Gen2Route::Route39,
+    Gen2Route::Route42,
+    Gen2Route::Route43,
+    Gen2Route::Route44,
+    Gen2Route::Route45,
+    Gen2Route::Route46,
+];
+
+impl Gen2Route {
+    fn connections(&self) -> &'static [Gen2Route] {
+        match self {
+            Gen2Route::Route29 => &[Gen2Route::Route30, Gen2Route::Route46],
+            Gen2Route::Route30 => &[Gen2Route::Route29, Gen2Route::Route31],
+            Gen2Route::Route31 => &[Gen2Route::Route30, Gen2Route::Route32, Gen2Route::Route36],
+            Gen2Route::Route32 => &[Gen2Route::Route36, Gen2Route::Route31, Gen2Route::Route33],
+            Gen2Route::Route33 => &[Gen2Route::Route32, Gen2Route::Route34],
+            Gen2Route::Route34 => &[Gen2Route::Route33, Gen2Route::Route35],
+            Gen2Route::Route35 => &[Gen2Route::Route34, Gen2Route::Route36],
+            Gen2Route::Route36 => &[
+                Gen2Route::Route35,
+                Gen2Route::Route31,
+                Gen2Route::Route32,
+                Gen2Route::Route37,
+            ],
+            Gen2Route::Route37 => &[Gen2Route::Route36, Gen2Route::Route38, Gen2Route::Route42],
+            Gen2Route::Route38 => &[Gen2Route::Route37, Gen2Route::Route39, Gen2Route::Route42],
+            Gen2Route::Route39 => &[Gen2Route::Route38],
+            Gen2Route::Route42 => &[Gen2Route::Route43, Gen2Route::Route44, Gen2Route::Route37],
+            Gen2Route::Route43 => &[Gen2Route::Route42],
+            Gen2Route::Route44 => &[Gen2Route::Route42, Gen2Route::Route45],
+            Gen2Route::Route45 => &[Gen2Route::Route44, Gen2Route::Route46],
+            Gen2Route::Route46 => &[Gen2Route::Route45, Gen2Route::Route29],
+        }
+    }
+}
+
+fn release_route(species: Species) -> Gen2Route {
+    match species {
+        Species::Raikou => Gen2Route::Route42,
+        Species::Entei => Gen2Route::Route37,
+        _ => Gen2Route::Route38,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen2RoamerPosition {
+    pub species: Species,
+    pub route: Gen2Route,
+}
+
+// Where the beasts land after fleeing the Burned Tower
+#[wasm_bindgen]
+pub fn gen2_roamer_release_positions(game: Gen2Game) -> Vec<Gen2RoamerPosition> {
+    game.roaming_beasts()
+        .iter()
+        .map(|&species| Gen2RoamerPosition {
+            species,
+            route: release_route(species),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen2RoamerMove {
+    pub advance: usize,
+    // None when the map isn't one the roamers can be on, such as a town
+    pub player_route: Option<Gen2Route>,
+    pub player_last_route: Option<Gen2Route>,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen2RoamerRouteOptions {
+    pub roamers: Vec<Gen2RoamerPosition>,
+    pub moves: Vec<Gen2RoamerMove>,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen2RoamerStep {
+    pub advance: usize,
+    pub roamers: Vec<Gen2RoamerPosition>,
+}
+
+fn jump_roamer(rng: &mut GameboyRng, player_route: Option<Gen2Route>) -> Gen2Route {
+    loop {
+        let [_, rand] = rng.next();
+        let route = ROAM_ROUTES[(rand & 0xf) as usize];
+        if Some(route) != player_route {
+            return route;
+        }
+    }
+}
+
+fn move_roamer(rng: &mut GameboyRng, route: Gen2Route, player_move: &Gen2RoamerMove) -> Gen2Route {
+    let connections = route.connections();
+    loop {
+        let [_, rand] = rng.next();
+        if rand & 0x1f == 0 {
+            return jump_roamer(rng, player_move.player_route);
+        }
+
+        let next_route = match connections.get((rand & 3) as usize) {
+            Some(next_route) => *next_route,
+            None => continue,
+        };
+
+        if Some(next_route) != player_move.player_last_route {
+            return next_route;
+        }
+    }
+}
+
+// Every roamer moves, in order, each time the player changes maps
+#[wasm_bindgen]
+pub fn gen2_predict_roamer_routes(
+    config: DivParams,
+    opts: &Gen2RoamerRouteOptions,
+) -> Vec<Gen2RoamerStep> {
+    let add_div = Div::new(config.adiv_index, config.adiv);
+    let sub_div = Div::new(config.sdiv_index, config.sdiv);
+    let rng = GameboyRng::new(config.state, add_div, sub_div);
+    let mut roamers = opts.roamers.clone();
+
+    opts.moves
+        .iter()
+        .map(|player_move| {
+            let mut rng = rng.clone();
+            for _ in 0..player_move.advance {
+                rng.next();
+            }
+
+            for roamer in roamers.iter_mut() {
+                roamer.route = move_roamer(&mut rng, roamer.route, player_move);
+            }
+
+            Gen2RoamerStep {
+                advance: player_move.advance,
+                roamers: roamers.clone(),
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen2RoamerOptions {
+    pub start_advance: usize,
+    pub end_advance: usize,
+    pub dv_delay: usize,
+    pub filter: Gen2DvFilter,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen2RoamerSpread {
+    pub spread: Gen2Spread,
+    pub dvs: Gen2Dvs,
+    pub hidden_power: HiddenPower,
+}
+
+// Roamers are released without DVs, which are rolled the first time they're encountered
+#[wasm_bindgen]
+pub fn gen2_generate_roamer_dvs(
+    config: DivParams,
+    opts: &Gen2RoamerOptions,
+) -> Vec<Gen2RoamerSpread> {
+    let add_div = Div::new(config.adiv_index, config.adiv);
+    let sub_div = Div::new(config.sdiv_index, config.sdiv);
+    let mut rng = GameboyRng::new(config.state, add_div, sub_div);
+    let mut spreads = Vec::new();
+    for advance in opts.start_advance..=opts.end_advance {
+        let mut poke_rng = rng.clone();
+        for _ in 0..opts.dv_delay {
+            poke_rng.next();
+        }
+
+        let [_, atkdef] = poke_rng.next();
+        let [_, spespc] = poke_rng.next();
+        let poke = Poke::new(atkdef, spespc);
+
+        if opts.filter.pass(&poke, GenderRatio::Genderless) {
+            let special_trait = poke.special_trait();
+            spreads.push(Gen2RoamerSpread {
+                spread: Gen2Spread {
+                    state: rng.state(),
+                    advance,
+                    shiny: special_trait == SpecialTrait::Shiny,
+                    max_dv: special_trait == SpecialTrait::MaxDv,
+                },
+                dvs: Gen2Dvs::from(&poke),
+                hidden_power: poke.hidden_power(),
+            });
+        }
+        rng.next();
+    }
+
+    spreads
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generators::gen2::HiddenPowerType;
+
+    const CONFIG: DivParams = DivParams {
+        adiv: 0x78,
+        sdiv: 0x78,
+        adiv_index: 468,
+        sdiv_index: 16139,
+        state: 0x9fe3,
+    };
+
+    #[test]
+    fn releases_beasts_per_game() {
+        assert_eq!(
+            gen2_roamer_release_positions(Gen2Game::Crystal),
+            vec![
+                Gen2RoamerPosition {
+                    species: Species::Raikou,
+                    route: Gen2Route::Route42,
+                },
+                Gen2RoamerPosition {
+                    species: Species::Entei,
+                    route: Gen2Route::Route37,
+                },
+            ]
+        );
+        assert_eq!(gen2_roamer_release_positions(Gen2Game::Gold).len(), 3);
+    }
+
+    #[test]
+    fn moves_roamers() {
+        let opts = Gen2RoamerRouteOptions {
+            roamers: gen2_roamer_release_positions(Gen2Game::Silver),
+            moves: (0..50)
+                .map(|step| Gen2RoamerMove {
+                    advance: step * 20,
+                    player_route: Some(Gen2Route::Route29),
+                    player_last_route: Some(Gen2Route::Route30),
+                })
+                .collect(),
+        };
+        let steps = gen2_predict_roamer_routes(CONFIG, &opts);
+
+        assert_eq!(steps.len(), 50);
+        let mut previous = opts.roamers.clone();
+        steps.iter().for_each(|step| {
+            step.roamers
+                .iter()
+                .zip(previous.iter())
+                .for_each(|(roamer, last)| {
+                    assert_eq!(roamer.species, last.species);
+                    let adjacent = last.route.connections().contains(&roamer.route);
+                    // Jumps can land anywhere other than the player's route
+                    assert!(adjacent || roamer.route != Gen2Route::Route29);
+                });
+            previous = step.roamers.clone();
+        });
+    }
+
+    #[test]
+    fn filters_roamer_dvs() {
+        let opts = Gen2RoamerOptions {
+            start_advance: 0,
+            end_advance: 2000,
+            dv_delay: 0,
+            filter: Gen2DvFilter {
+                hidden_power_type: Some(HiddenPowerType::Ice),
+                ..Default::default()
+            },
+        };
+        let results = gen2_generate_roamer_dvs(CONFIG, &opts);
+
+        assert!(!results.is_empty());
+        assert!(
+            results
+                .iter()
+                .all(|result| result.hidden_power.hp_type == HiddenPowerType::Ice)
+        );
+    }
+}
@@ -1,18 +1,21 @@
 use super::poke::{Poke, SpecialTrait, possible_special_trait};
-use super::{DivParams, Gen2PokeFilter, Gen2Spread};
+use super::{DivParams, Gen2DivVariant, Gen2Game, Gen2PokeFilter, Gen2Spread, Gen2StarterTiming};
 use crate::rng::gameboy::{Div, GameboyRng, Offset};
 use wasm_bindgen::prelude::*;
 
 fn generate_starter_rands(
     rng: &GameboyRng,
+    timing: &Gen2StarterTiming,
     a_div_1_offset: Offset,
     s_div_1_offset: Offset,
     a_div_2_offset: Offset,
     s_div_2_offset: Offset,
 ) -> [[u8; 2]; 2] {
     let mut poke_rng = rng.clone();
-    // Advance once for the A press
-    poke_rng.next();
+    // Advance for the A press
+    for _ in 0..timing.a_press_rands {
+        poke_rng.next();
+    }
     let r_add_0 = poke_rng.r_add;
     let r_sub_0 = poke_rng.r_sub;
     // Get the normal rand[1] values
@@ -20,17 +23,21 @@ fn generate_starter_rands(
     let normal_a_div_1 = poke_rng.add_div.value();
     let normal_s_div_1 = poke_rng.sub_div.value();
 
-    let poke_a_div_1 = normal_a_div_1.wrapping_mul(2).wrapping_add(10);
+    let poke_a_div_1 = normal_a_div_1
+        .wrapping_mul(timing.div_scale)
+        .wrapping_add(timing.div_delay);
     let poke_a_div_1 = a_div_1_offset.apply(poke_a_div_1);
 
-    let poke_s_div_1 = normal_s_div_1.wrapping_mul(2).wrapping_add(10);
+    let poke_s_div_1 = normal_s_div_1
+        .wrapping_mul(timing.div_scale)
+        .wrapping_add(timing.div_delay);
     let poke_s_div_1 = s_div_1_offset.apply(poke_s_div_1);
 
     let poke_rand_1 = GameboyRng::advance_state(r_add_0, r_sub_0, poke_a_div_1, poke_s_div_1);
 
+    // DIV keeps ticking from the first roll to the second by the same amount on both sides
+    let poke_a_div_2 = poke_a_div_1.wrapping_sub(normal_a_div_1);
     let poke_s_div_2 = poke_s_div_1.wrapping_sub(normal_s_div_1);
-    // TODO: Fix this
-    let poke_a_div_2 = poke_s_div_2;
 
     let poke_s_div_2 = s_div_2_offset.apply(poke_s_div_2);
     let poke_a_div_2 = a_div_2_offset.apply(poke_a_div_2);
@@ -41,8 +48,11 @@ fn generate_starter_rands(
     [poke_rand_1, poke_rand_2]
 }
 
-fn potential_starters(rng: &GameboyRng) -> Vec<Poke> {
+pub fn potential_starters(game: Gen2Game, rng: &GameboyRng) -> Vec<(Gen2DivVariant, Poke)> {
     let mut result = Vec::new();
+    let Some(timing) = game.starter_timing() else {
+        return result;
+    };
 
     for a_div_1_offset in -1..=1 {
         for s_div_1_offset in -1..=1 {
@@ -50,14 +60,20 @@ fn potential_starters(rng: &GameboyRng) -> Vec<Poke> {
                 for s_div_2_offset in -1..=1 {
                     let [[_, atkdef], [_, spespc]] = generate_starter_rands(
                         rng,
+                        &timing,
                         Offset::from_i8(a_div_1_offset),
                         Offset::from_i8(s_div_1_offset),
                         Offset::from_i8(a_div_2_offset),
                         Offset::from_i8(s_div_2_offset),
                     );
 
-                    let poke = Poke::new(atkdef, spespc);
-                    result.push(poke);
+                    let variant = Gen2DivVariant::Starter {
+                        a_div_1_offset,
+                        s_div_1_offset,
+                        a_div_2_offset,
+                        s_div_2_offset,
+                    };
+                    result.push((variant, Poke::new(atkdef, spespc)));
                 }
             }
         }
@@ -66,9 +82,9 @@ fn potential_starters(rng: &GameboyRng) -> Vec<Poke> {
     result
 }
 
-fn has_potential_special_starter(rng: &GameboyRng) -> SpecialTrait {
-    let pokes = potential_starters(rng);
-    possible_special_trait(&pokes)
+fn has_potential_special_starter(game: Gen2Game, rng: &GameboyRng) -> SpecialTrait {
+    let pokes = potential_starters(game, rng);
+    possible_special_trait(pokes.iter().map(|(_, poke)| poke))
 }
 
 #[wasm_bindgen]
@@ -77,13 +93,30 @@ pub fn crystal_generate_starters(
     start_advance: usize,
     end_advance: usize,
     filter: Gen2PokeFilter,
+) -> Vec<Gen2Spread> {
+    gen2_generate_starters(
+        Gen2Game::Crystal,
+        config,
+        start_advance,
+        end_advance,
+        filter,
+    )
+}
+
+#[wasm_bindgen]
+pub fn gen2_generate_starters(
+    game: Gen2Game,
+    config: DivParams,
+    start_advance: usize,
+    end_advance: usize,
+    filter: Gen2PokeFilter,
 ) -> Vec<Gen2Spread> {
     let add_div = Div::new(config.adiv_index, config.adiv);
     let sub_div = Div::new(config.sdiv_index, config.sdiv);
     let mut rng = GameboyRng::new(config.state, add_div, sub_div);
     let mut spreads = Vec::new();
     for advance in start_advance..=end_advance {
-        let special_trait = has_potential_special_starter(&rng);
+        let special_trait = has_potential_special_starter(game, &rng);
         if filter == special_trait {
             spreads.push(Gen2Spread {
                 state: rng.state(),
@@ -96,3 +129,57 @@ pub fn crystal_generate_starters(
     }
     spreads
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CONFIG: DivParams = DivParams {
+        adiv: 0x78,
+        sdiv: 0x78,
+        adiv_index: 468,
+        sdiv_index: 16139,
+        state: 0x9fe3,
+    };
+
+    fn spread(advance: usize, state: u16, shiny: bool) -> Gen2Spread {
+        Gen2Spread {
+            state,
+            advance,
+            shiny,
+            max_dv: !shiny,
+        }
+    }
+
+    #[test]
+    fn generates_crystal_starters() {
+        let shiny = crystal_generate_starters(CONFIG, 0, 5000, Gen2PokeFilter::Shiny);
+        assert_eq!(
+            shiny,
+            [
+                spread(792, 0x9fc0, true),
+                spread(3975, 0x5783, true),
+                spread(4695, 0x6984, true),
+                spread(4737, 0x4a87, true),
+            ]
+        );
+
+        let max_dv = crystal_generate_starters(CONFIG, 0, 20000, Gen2PokeFilter::MaxDv);
+        assert_eq!(
+            max_dv,
+            [
+                spread(6701, 0xb8d7, false),
+                spread(15578, 0xf3d6, false),
+                spread(18430, 0x46e0, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn gold_and_silver_starters_are_not_predicted() {
+        for game in [Gen2Game::Gold, Gen2Game::Silver] {
+            let results = gen2_generate_starters(game, CONFIG, 0, 1000, Gen2PokeFilter::Any);
+            assert!(results.is_empty());
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use super::dv_searcher::Gen2Dvs;
+use super::poke::{HiddenPower, Poke, SpecialTrait};
+use super::{DivParams, Gen2DvFilter, Gen2Spread};
+use crate::rng::gameboy::{Div, GameboyRng};
+use crate::{Gender, Species};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+const GRASS_SLOT_THRESHOLDS: [u8; 7] = [30, 60, 80, 90, 95, 99, 100];
+const WATER_SLOT_THRESHOLDS: [u8; 3] = [60, 90, 100];
+
+// 35%, 65%, 85% and 95% of 0xff
+const SURF_LEVEL_THRESHOLDS: [u8; 4] = [89, 165, 216, 242];
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Gen2WildEncounterType {
+    Grass,
+    Surf,
+}
+
+impl Gen2WildEncounterType {
+    fn slot_thresholds(&self) -> &'static [u8] {
+        match self {
+            Gen2WildEncounterType::Grass => &GRASS_SLOT_THRESHOLDS,
+            Gen2WildEncounterType::Surf => &WATER_SLOT_THRESHOLDS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen2WildSlot {
+    pub species: Species,
+    pub level: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct CrystalWildOptions {
+    pub start_advance: usize,
+    pub end_advance: usize,
+    pub encounter_type: Gen2WildEncounterType,
+    // None when the encounter is forced, such as with Sweet Scent
+    pub encounter_rate: Option<u8>,
+    pub slots: Vec<Gen2WildSlot>,
+    pub dv_delay: usize,
+    pub slot_filter: Option<Vec<u8>>,
+    pub filter: Gen2DvFilter,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen2WildSpread {
+    pub spread: Gen2Spread,
+    pub slot: u8,
+    pub species: Species,
+    pub level: u8,
+    pub dvs: Gen2Dvs,
+    pub gender: Gender,
+    pub hidden_power: HiddenPower,
+    pub unown_letter: Option<char>,
+}
+
+fn wild_slot(encounter_type: Gen2WildEncounterType, rng: &mut GameboyRng) -> u8 {
+    let rand = loop {
+        let [_, rand] = rng.next();
+        if rand < 100 {
+            break rand + 1;
+        }
+    };
+
+    encounter_type
+        .slot_thresholds()
+        .iter()
+        .position(|&threshold| threshold >= rand)
+        .unwrap_or_default() as u8
+}
+
+fn wild_level(encounter_type: Gen2WildEncounterType, level: u8, rng: &mut GameboyRng) -> u8 {
+    if encounter_type != Gen2WildEncounterType::Surf {
+        return level;
+    }
+
+    let [_, rand] = rng.next();
+    let bonus = SURF_LEVEL_THRESHOLDS
+        .iter()
+        .take_while(|&&threshold| rand >= threshold)
+        .count();
+    level + bonus as u8
+}
+
+fn generate_crystal_wild(
+    rng: &GameboyRng,
+    advance: usize,
+    opts: &CrystalWildOptions,
+) -> Option<Gen2WildSpread> {
+    let state = rng.state();
+    let mut rng = rng.clone();
+
+    if let Some(encounter_rate) = opts.encounter_rate {
+        let [_, rand] = rng.next();
+        if rand >= encounter_rate {
+            return None;
+        }
+    }
+
+    let slot = wild_slot(opts.encounter_type, &mut rng);
+    if let Some(slot_filter) = &opts.slot_filter {
+        if !slot_filter.contains(&slot) {
+            return None;
+        }
+    }
+
+    let wild_slot = opts.slots.get(slot as usize)?;
+    let level = wild_level(opts.encounter_type, wild_slot.level, &mut rng);
+
+    for _ in 0..opts.dv_delay {
+        rng.next();
+    }
+
+    let [_, atkdef] = rng.next();
+    let [_, spespc] = rng.next();
+    let poke = Poke::new(atkdef, spespc);
+    if !opts.filter.pass(&poke, wild_slot.species.gender_ratio()) {
+        return None;
+    }
+
+    let special_trait = poke.special_trait();
+
+    Some(Gen2WildSpread {
+        spread: Gen2Spread {
+            state,
+            advance,
+            shiny: special_trait == SpecialTrait::Shiny,
+            max_dv: special_trait == SpecialTrait::MaxDv,
+        },
+        slot,
+        species: wild_slot.species,
+        level,
+        dvs: Gen2Dvs::from(&poke),
+        gender: poke.gender(wild_slot.species.gender_ratio()),
+        hidden_power: poke.hidden_power(),
+        unown_letter: (wild_slot.species == Species::Unown).then(|| poke.unown_letter()),
+    })
+}
+
+#[wasm_bindgen]
+pub fn crystal_generate_wild(config: DivParams, opts: &CrystalWildOptions) -> Vec<Gen2WildSpread> {
+    let add_div = Div::new(config.adiv_index, config.adiv);
+    let sub_div = Div::new(config.sdiv_index, config.sdiv);
+    let mut rng = GameboyRng::new(config.state, add_div, sub_div);
+    let mut spreads = Vec::new();
+    for advance in opts.start_advance..=opts.end_advance {
+        if let Some(spread) = generate_crystal_wild(&rng, advance, opts) {
+            spreads.push(spread);
+        }
+        rng.next();
+    }
+
+    spreads
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Gender;
+    use crate::generators::gen2::{HiddenPower, HiddenPowerType};
+
+    const CONFIG: DivParams = DivParams {
+        adiv: 0x78,
+        sdiv: 0x78,
+        adiv_index: 468,
+        sdiv_index: 16139,
+        state: 0x9fe3,
+    };
+
+    fn route_29_slots() -> Vec<Gen2WildSlot> {
+        [
+            (Species::Pidgey, 2),
+            (Species::Sentret, 2),
+            (Species::Pidgey, 3),
+            (Species::Sentret, 3),
+            (Species::Rattata, 2),
+            (Species::Hoppip, 3),
+            (Species::Hoppip, 3),
+        ]
+        .into_iter()
+        .map(|(species, level)| Gen2WildSlot { species, level })
+        .collect()
+    }
+
+    #[test]
+    fn generates_wild() {
+        let opts = CrystalWildOptions {
+            start_advance: 0,
+            end_advance: 200,
+            encounter_type: Gen2WildEncounterType::Grass,
+            encounter_rate: Some(25),
+            slots: route_29_slots(),
+            dv_delay: 0,
+            slot_filter: None,
+            filter: Gen2DvFilter::default(),
+        };
+        let results = crystal_generate_wild(CONFIG, &opts);
+
+        assert!(!results.is_empty());
+        results.iter().for_each(|result| {
+            let slot = &opts.slots[result.slot as usize];
+            assert_eq!(result.species, slot.species);
+            assert_eq!(result.level, slot.level);
+        });
+    }
+
+    #[test]
+    fn filters_wild() {
+        let opts = CrystalWildOptions {
+            start_advance: 0,
+            end_advance: 5000,
+            encounter_type: Gen2WildEncounterType::Surf,
+            encounter_rate: None,
+            slots: [Species::Tentacool, Species::Tentacool, Species::Tentacruel]
+                .into_iter()
+                .map(|species| Gen2WildSlot { species, level: 20 })
+                .collect(),
+            dv_delay: 3,
+            slot_filter: Some(vec![2]),
+            filter: Gen2DvFilter {
+                hidden_power_type: Some(HiddenPowerType::Water),
+                ..Default::default()
+            },
+        };
+        let results = crystal_generate_wild(CONFIG, &opts);
+
+        assert_eq!(results.len(), 36);
+        assert_eq!(
+            results[0],
+            Gen2WildSpread {
+                spread: Gen2Spread {
+                    state: 0xda5e,
+                    advance: 104,
+                    shiny: false,
+                    max_dv: false,
+                },
+                slot: 2,
+                species: Species::Tentacruel,
+                level: 20,
+                dvs: Gen2Dvs {
+                    hp: 7,
+                    atk: 6,
+                    def: 9,
+                    spe: 15,
+                    spc: 15,
+                },
+                gender: Gender::Female,
+                hidden_power: HiddenPower {
+                    hp_type: HiddenPowerType::Water,
+                    power: 50,
+                },
+                unown_letter: None,
+            }
+        );
+
+        results.iter().for_each(|result| {
+            assert_eq!(result.hidden_power.hp_type, HiddenPowerType::Water);
+            assert_eq!(result.species, Species::Tentacruel);
+            assert!((20..=24).contains(&result.level));
+        });
+    }
+}
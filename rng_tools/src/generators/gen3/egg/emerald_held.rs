@@ -38,6 +38,21 @@ pub struct Gen3HeldEgg {
     pub match_call: PokeNavTrainer,
 }
 
+// Nidoran♀ and Illumise eggs can hatch into Nidoran♂ and Volbeat
+pub fn egg_gender(species: Species, pid: u32) -> Gender {
+    match species {
+        Species::NidoranF | Species::Illumise => {
+            let val = (pid & 0xFFFF) as u16;
+            if val < 0x8000 {
+                Gender::Female
+            } else {
+                Gender::Male
+            }
+        }
+        _ => species.gender_from_pid(pid),
+    }
+}
+
 impl Gen3HeldEgg {
     fn from_pid(
         egg: &Gen3HeldEggPid,
@@ -59,22 +74,10 @@ impl Gen3HeldEgg {
             advance = advance.saturating_add(delay.unsigned_abs() as usize);
         }
 
-        let gender = match species {
-            Species::NidoranF | Species::Illumise => {
-                let val = (pid & 0xFFFF) as u16;
-                if val < 0x8000 {
-                    Gender::Female
-                } else {
-                    Gender::Male
-                }
-            }
-            _ => species.gender_from_pid(pid),
-        };
-
         Self {
             pid,
             advance,
-            gender,
+            gender: egg_gender(species, pid),
             redraws: egg.redraws,
             calibration: egg.calibration,
             nature: Nature::from_pid(pid),
@@ -95,18 +98,22 @@ pub struct Egg3HeldFilters {
 
 impl Egg3HeldFilters {
     fn pass_filter(&self, egg: &Gen3HeldEgg) -> bool {
-        if self.shiny && !egg.shiny {
+        self.pass(egg.shiny, egg.nature, egg.gender)
+    }
+
+    pub fn pass(&self, shiny: bool, nature: Nature, gender: Gender) -> bool {
+        if self.shiny && !shiny {
             return false;
         }
 
-        if let Some(nature) = self.nature {
-            if egg.nature != nature {
+        if let Some(wanted_nature) = self.nature {
+            if nature != wanted_nature {
                 return false;
             }
         }
 
-        if let Some(gender) = self.gender {
-            if egg.gender != gender {
+        if let Some(wanted_gender) = self.gender {
+            if gender != wanted_gender {
                 return false;
             }
         }
@@ -0,0 +1,195 @@
+use super::{
+    Egg3HeldOptions, Egg3PickupOptions, Egg3PickupState, Gen3HeldEgg, emerald_egg_held_states,
+    emerald_egg_pickup_states,
+};
+use crate::{Gender, InheritedIvs, Nature};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Egg3PlannerOptions {
+    pub held: Egg3HeldOptions,
+    pub pickup: Egg3PickupOptions,
+    // 16 when walking, 8 on the Acro or Mach Bike
+    pub frames_per_step: usize,
+    // From talking to the daycare man until the pickup IVs are rolled
+    pub daycare_talk_frames: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Egg3PlannerSchedule {
+    pub redraws: usize,
+    pub held_advance: usize,
+    // Steps to take after the egg is held before talking to the daycare man
+    pub steps: usize,
+    pub talk_advance: usize,
+    pub pickup_advance: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen3PlannedEgg {
+    pub pid: u32,
+    pub nature: Nature,
+    pub shiny: bool,
+    pub gender: Gender,
+    pub ability: u8,
+    pub ivs: InheritedIvs,
+    pub schedule: Egg3PlannerSchedule,
+}
+
+fn plan_egg(
+    held: &Gen3HeldEgg,
+    pickup: &Egg3PickupState,
+    opts: &Egg3PlannerOptions,
+) -> Option<Gen3PlannedEgg> {
+    let talk_advance = pickup.advance.checked_sub(opts.daycare_talk_frames)?;
+    let walk_frames = talk_advance.checked_sub(held.advance)?;
+
+    Some(Gen3PlannedEgg {
+        pid: held.pid,
+        nature: held.nature,
+        shiny: held.shiny,
+        gender: held.gender,
+        ability: held.ability,
+        ivs: pickup.ivs,
+        schedule: Egg3PlannerSchedule {
+            redraws: held.redraws,
+            held_advance: held.advance,
+            steps: walk_frames / opts.frames_per_step.max(1),
+            talk_advance,
+            pickup_advance: pickup.advance,
+        },
+    })
+}
+
+// Each held egg is planned with its earliest reachable pickup.
+// Eggs are ranked by how long they take to hit, then by the fewest redraws
+#[wasm_bindgen]
+pub fn emerald_egg_planner_states(opts: &Egg3PlannerOptions) -> Vec<Gen3PlannedEgg> {
+    let held_eggs = emerald_egg_held_states(&opts.held);
+    let pickups = emerald_egg_pickup_states(&opts.pickup);
+
+    // Pickups are already in advance order
+    let mut eggs = held_eggs
+        .iter()
+        .filter_map(|held| {
+            pickups
+                .iter()
+                .find_map(|pickup| plan_egg(held, pickup, opts))
+        })
+        .collect::<Vec<Gen3PlannedEgg>>();
+    eggs.sort_by_key(|egg| {
+        (
+            egg.schedule.pickup_advance,
+            egg.schedule.redraws,
+            egg.schedule.held_advance,
+        )
+    });
+    eggs
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gen3::{Compatability, Egg3HeldFilters, Gen3PickupMethod};
+    use crate::{IvFilter, Ivs, PartialIvs, Species};
+
+    fn planner_options() -> Egg3PlannerOptions {
+        Egg3PlannerOptions {
+            held: Egg3HeldOptions {
+                delay: 0,
+                initial_advances: 2000,
+                max_advances: 200,
+                female_has_everstone: false,
+                female_nature: Nature::Hardy,
+                has_roamer: false,
+                has_lightning_rod: false,
+                registered_trainers: vec![],
+                calibration: 18,
+                min_redraw: 0,
+                max_redraw: 2,
+                compatability: Compatability::GetAlong,
+                tid: 0,
+                sid: 0,
+                egg_species: Species::Bulbasaur,
+                filters: Egg3HeldFilters {
+                    shiny: false,
+                    nature: None,
+                    gender: None,
+                },
+                lua_adjustment: false,
+                filter_impossible_to_hit: true,
+            },
+            pickup: Egg3PickupOptions {
+                delay: 0,
+                seed: 0,
+                initial_advances: 2000,
+                max_advances: 600,
+                parent_ivs: [PartialIvs::default(), PartialIvs::default()],
+                method: Gen3PickupMethod::EmeraldBred,
+                filter: IvFilter {
+                    min_ivs: Ivs::new_all0(),
+                    max_ivs: Ivs::new_all31(),
+                },
+                lua_adjustment: false,
+            },
+            frames_per_step: 16,
+            daycare_talk_frames: 120,
+        }
+    }
+
+    #[test]
+    fn joins_held_and_pickup_eggs() {
+        let opts = planner_options();
+        let held_eggs = emerald_egg_held_states(&opts.held);
+        let pickups = emerald_egg_pickup_states(&opts.pickup);
+        let eggs = emerald_egg_planner_states(&opts);
+
+        assert!(!eggs.is_empty());
+        eggs.iter().for_each(|egg| {
+            let schedule = egg.schedule;
+            assert!(schedule.held_advance + schedule.steps * 16 <= schedule.talk_advance);
+            assert_eq!(schedule.talk_advance + 120, schedule.pickup_advance);
+            assert!(held_eggs.iter().any(|held| held.pid == egg.pid
+                && held.advance == schedule.held_advance
+                && held.redraws == schedule.redraws));
+            assert!(
+                pickups.iter().any(
+                    |pickup| pickup.ivs == egg.ivs && pickup.advance == schedule.pickup_advance
+                )
+            );
+        });
+    }
+
+    #[test]
+    fn ranks_by_time_to_hit() {
+        let eggs = emerald_egg_planner_states(&planner_options());
+
+        assert!(eggs.windows(2).all(|eggs| {
+            (eggs[0].schedule.pickup_advance, eggs[0].schedule.redraws)
+                <= (eggs[1].schedule.pickup_advance, eggs[1].schedule.redraws)
+        }));
+    }
+
+    #[test]
+    fn plans_each_held_egg_once() {
+        let opts = planner_options();
+        let held_eggs = emerald_egg_held_states(&opts.held);
+        let pickups = emerald_egg_pickup_states(&opts.pickup);
+        let eggs = emerald_egg_planner_states(&opts);
+
+        assert!(eggs.len() <= held_eggs.len());
+        eggs.iter().for_each(|egg| {
+            let schedule = egg.schedule;
+            let earliest = pickups
+                .iter()
+                .find(|pickup| pickup.advance >= schedule.held_advance + 120)
+                .unwrap();
+            assert_eq!(schedule.pickup_advance, earliest.advance);
+        });
+    }
+}
@@ -1,5 +1,11 @@
 mod emerald_held;
 mod emerald_pickup;
+mod emerald_planner;
+mod rs_frlg_held;
+mod rs_frlg_pickup;
 
 pub use emerald_held::*;
 pub use emerald_pickup::*;
+pub use emerald_planner::*;
+pub use rs_frlg_held::*;
+pub use rs_frlg_pickup::*;
@@ -0,0 +1,127 @@
+use super::Gen3Game;
+use crate::rng::Rng;
+use crate::rng::lcrng::Feebasrng;
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+const FEEBAS_TILE_COUNT: usize = 6;
+const FISHING_TILE_COUNT: u16 = 447;
+
+// Spots 1 to 3 are inaccessible spots at the top of the map, so the game rerolls them
+const FIRST_REACHABLE_TILE: u16 = 4;
+
+// First row, last row and first tile number of each third of Route 119
+const ROUTE_119_SECTIONS: [(u8, u8, u16); 3] = [(0, 45, 0), (46, 91, 131), (92, 139, 298)];
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct FeebasTile {
+    // Water tiles are numbered left to right, top to bottom, starting at 1
+    pub tile: u16,
+    pub section: u8,
+    pub min_y: u8,
+    pub max_y: u8,
+}
+
+impl FeebasTile {
+    fn new(tile: u16) -> Self {
+        let section = ROUTE_119_SECTIONS
+            .iter()
+            .rposition(|&(_, _, first_tile)| tile > first_tile)
+            .unwrap_or_default();
+        let (min_y, max_y, _) = ROUTE_119_SECTIONS[section];
+        Self {
+            tile,
+            section: section as u8,
+            min_y,
+            max_y,
+        }
+    }
+}
+
+fn generate_feebas_tiles(feebas_seed: u16) -> [u16; FEEBAS_TILE_COUNT] {
+    let mut rng = Feebasrng::new(feebas_seed.into());
+    let mut tiles = [0; FEEBAS_TILE_COUNT];
+    for tile in tiles.iter_mut() {
+        *tile = loop {
+            let rand = rng.rand::<u16>() % FISHING_TILE_COUNT;
+            let rand = if rand == 0 { FISHING_TILE_COUNT } else { rand };
+            if rand >= FIRST_REACHABLE_TILE {
+                break rand;
+            }
+        };
+    }
+    tiles
+}
+
+// Ruby, Sapphire and Emerald share the Feebas routine, and FireRed and LeafGreen have no Feebas
+#[wasm_bindgen]
+pub fn gen3_feebas_tiles(game: Gen3Game, feebas_seed: u16) -> Vec<FeebasTile> {
+    if game.is_frlg() {
+        return vec![];
+    }
+
+    generate_feebas_tiles(feebas_seed)
+        .into_iter()
+        .map(FeebasTile::new)
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct FeebasSeedSearchOptions {
+    pub game: Gen3Game,
+    pub tiles: Vec<u16>,
+}
+
+// Candidate seeds can be passed to emerald_sid_from_feebas_seed or rs_sid_from_feebas_seed
+#[wasm_bindgen]
+pub fn gen3_feebas_seeds_from_tiles(opts: &FeebasSeedSearchOptions) -> Vec<u16> {
+    if opts.game.is_frlg() {
+        return vec![];
+    }
+
+    (0..=u16::MAX)
+        .filter(|&feebas_seed| {
+            let tiles = generate_feebas_tiles(feebas_seed);
+            opts.tiles.iter().all(|tile| tiles.contains(tile))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_feebas_tiles() {
+        let tiles = gen3_feebas_tiles(Gen3Game::Emerald, 0xad4f);
+
+        assert_eq!(tiles.len(), FEEBAS_TILE_COUNT);
+        tiles.iter().for_each(|tile| {
+            assert!((FIRST_REACHABLE_TILE..=FISHING_TILE_COUNT).contains(&tile.tile));
+            let (min_y, max_y, first_tile) = ROUTE_119_SECTIONS[tile.section as usize];
+            assert!(tile.tile > first_tile);
+            assert_eq!((tile.min_y, tile.max_y), (min_y, max_y));
+        });
+        assert_eq!(gen3_feebas_tiles(Gen3Game::Ruby, 0xad4f), tiles);
+        assert!(gen3_feebas_tiles(Gen3Game::FireRed, 0xad4f).is_empty());
+    }
+
+    #[test]
+    fn finds_seeds_from_tiles() {
+        let tiles = generate_feebas_tiles(0xebf6);
+        let results = gen3_feebas_seeds_from_tiles(&FeebasSeedSearchOptions {
+            game: Gen3Game::Sapphire,
+            tiles: tiles[..3].to_vec(),
+        });
+
+        assert!(results.contains(&0xebf6));
+        assert!(results.iter().all(|&seed| {
+            tiles[..3]
+                .iter()
+                .all(|tile| generate_feebas_tiles(seed).contains(tile))
+        }));
+    }
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Gen3Game {
+    Ruby,
+    Sapphire,
+    Emerald,
+    FireRed,
+    LeafGreen,
+}
+
+impl Gen3Game {
+    pub fn is_rs(&self) -> bool {
+        matches!(self, Gen3Game::Ruby | Gen3Game::Sapphire)
+    }
+
+    pub fn is_frlg(&self) -> bool {
+        matches!(self, Gen3Game::FireRed | Gen3Game::LeafGreen)
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{Gender, Nature, Species};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+
+// A team member the game generates before the shadow Pokémon.
+// The game rerolls its PID until the nature and gender match, and None accepts anything.
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ShadowLock {
+    pub species: Species,
+    pub nature: Option<Nature>,
+    pub gender: Option<Gender>,
+}
+
+impl ShadowLock {
+    pub fn pass(&self, pid: u32) -> bool {
+        let nature_matches = self
+            .nature
+            .is_none_or(|nature| Nature::from_pid(pid) == nature);
+        let gender_matches = self
+            .gender
+            .is_none_or(|gender| self.species.gender_from_pid(pid) == gender);
+        nature_matches && gender_matches
+    }
+
+    // Every nature is reachable, but the gender has to be one the species can have
+    pub fn is_possible(&self) -> bool {
+        let gender_ratio = self.species.gender_ratio();
+        self.gender
+            .is_none_or(|gender| (0..=u8::MAX).any(|byte| gender_ratio.gender(byte) == gender))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum ShadowTeamMember {
+    Lock(ShadowLock),
+    // An earlier shadow Pokémon in the team that the player already snagged.
+    // It's still rolled to keep the rands in step, but nothing about it is rerolled.
+    CaughtShadow,
+}
+
+impl ShadowTeamMember {
+    pub fn is_possible(&self) -> bool {
+        match self {
+            ShadowTeamMember::Lock(lock) => lock.is_possible(),
+            ShadowTeamMember::CaughtShadow => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum GamecubeGame {
+    Colosseum,
+    Xd,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ShadowTeam {
+    pub game: GamecubeGame,
+    pub shadow: Species,
+    // In the order the game generates them
+    pub members: Vec<ShadowTeamMember>,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum ShadowEncounter {
+    XdTeddiursa,
+    // For encounters missing from the table, using the enemy team's natures and genders
+    Custom(ShadowTeam),
+}
+
+impl ShadowEncounter {
+    pub fn team(&self) -> ShadowTeam {
+        match self {
+            // The first shadow in XD is generated alone
+            ShadowEncounter::XdTeddiursa => ShadowTeam {
+                game: GamecubeGame::Xd,
+                shadow: Species::Teddiursa,
+                members: vec![],
+            },
+            ShadowEncounter::Custom(team) => team.clone(),
+        }
+    }
+}
+
+impl ShadowTeam {
+    // A lock the game can never satisfy would reroll forever
+    pub fn is_possible(&self) -> bool {
+        self.members.iter().all(ShadowTeamMember::is_possible)
+    }
+}
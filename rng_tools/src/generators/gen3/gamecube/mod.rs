@@ -0,0 +1,5 @@
+mod locks;
+mod shadow;
+
+pub use locks::*;
+pub use shadow::*;
@@ -0,0 +1,459 @@
+use super::{GamecubeGame, ShadowEncounter, ShadowTeam, ShadowTeamMember};
+use crate::{
+    AbilityType, Gender, Ivs, Nature, PkmFilter, PkmState, gen3_shiny,
+    rng::{Rng, StateIterator, lcrng::Xdrng},
+};
+use itertools::iproduct;
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+// How far back the searcher looks for the seed that starts the battle
+const MAX_RANDS_BEFORE_SHADOW: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ShadowState {
+    pub advance: usize,
+    pub seed: u32,
+    pub pid: u32,
+    pub ivs: Ivs,
+    pub ability: AbilityType,
+    pub gender: Gender,
+    pub nature: Nature,
+    pub shiny: bool,
+    // PIDs thrown away by the locks and the shadow Pokémon's shiny check
+    pub rerolls: usize,
+}
+
+impl PkmState for ShadowState {
+    fn ivs(&self) -> &Ivs {
+        &self.ivs
+    }
+
+    fn shiny(&self) -> bool {
+        self.shiny
+    }
+
+    fn nature(&self) -> Nature {
+        self.nature
+    }
+
+    fn ability(&self) -> AbilityType {
+        self.ability
+    }
+
+    fn gender(&self) -> Gender {
+        self.gender
+    }
+}
+
+fn rand_pid(rng: &mut Xdrng) -> u32 {
+    let high = rng.rand::<u16>() as u32;
+    let low = rng.rand::<u16>() as u32;
+    (high << 16) | low
+}
+
+// Returns the PID and how many PIDs were rerolled
+fn rand_pid_until(rng: &mut Xdrng, pass: impl Fn(u32) -> bool) -> (u32, usize) {
+    let mut rerolls = 0;
+    loop {
+        let pid = rand_pid(rng);
+        if pass(pid) {
+            return (pid, rerolls);
+        }
+        rerolls += 1;
+    }
+}
+
+// The enemy team members only need their PIDs, but still roll IVs and ability first
+fn generate_member(
+    rng: &mut Xdrng,
+    member: &ShadowTeamMember,
+    enemy_tid: u16,
+    enemy_sid: u16,
+) -> usize {
+    rng.advance(3);
+    match member {
+        ShadowTeamMember::Lock(lock) => {
+            let (_, rerolls) = rand_pid_until(rng, |pid| {
+                lock.pass(pid) && !gen3_shiny(pid, enemy_tid, enemy_sid)
+            });
+            rerolls
+        }
+        ShadowTeamMember::CaughtShadow => {
+            rand_pid(rng);
+            0
+        }
+    }
+}
+
+struct GeneratedShadow {
+    // The state that rolls the first IV rand
+    iv_seed: u32,
+    pid: u32,
+    ivs: Ivs,
+    ability: AbilityType,
+    rerolls: usize,
+}
+
+fn generate_shadow(mut rng: Xdrng, team: &ShadowTeam, tid: u16, sid: u16) -> GeneratedShadow {
+    let enemy_tid = rng.rand::<u16>();
+    let enemy_sid = rng.rand::<u16>();
+    let lock_rerolls = team
+        .members
+        .iter()
+        .map(|member| generate_member(&mut rng, member, enemy_tid, enemy_sid))
+        .sum::<usize>();
+
+    let iv_seed = rng.seed();
+    let iv1 = rng.rand::<u16>();
+    let iv2 = rng.rand::<u16>();
+    let ability = AbilityType::from((rng.rand::<u16>() & 1) as u8);
+    // XD keeps shadow Pokémon from being shiny for the player,
+    // but Colosseum only checks the enemy trainer's IDs
+    let (shiny_tid, shiny_sid) = match team.game {
+        GamecubeGame::Xd => (tid, sid),
+        GamecubeGame::Colosseum => (enemy_tid, enemy_sid),
+    };
+    let (pid, shiny_rerolls) =
+        rand_pid_until(&mut rng, |pid| !gen3_shiny(pid, shiny_tid, shiny_sid));
+
+    GeneratedShadow {
+        iv_seed,
+        pid,
+        ivs: Ivs::new_g3(iv1, iv2),
+        ability,
+        rerolls: lock_rerolls + shiny_rerolls,
+    }
+}
+
+fn shadow_state(
+    generated: GeneratedShadow,
+    team: &ShadowTeam,
+    (tid, sid): (u16, u16),
+    seed: u32,
+    advance: usize,
+) -> ShadowState {
+    ShadowState {
+        advance,
+        seed,
+        pid: generated.pid,
+        ivs: generated.ivs,
+        ability: generated.ability,
+        gender: team.shadow.gender_from_pid(generated.pid),
+        nature: Nature::from_pid(generated.pid),
+        shiny: gen3_shiny(generated.pid, tid, sid),
+        rerolls: generated.rerolls,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ShadowGeneratorOptions {
+    pub seed: u32,
+    pub offset: usize,
+    pub initial_advances: usize,
+    pub max_advances: usize,
+    pub encounter: ShadowEncounter,
+    pub tid: u16,
+    pub sid: u16,
+    pub filter: PkmFilter,
+}
+
+#[wasm_bindgen]
+pub fn gamecube_shadow_states(opts: &ShadowGeneratorOptions) -> Vec<ShadowState> {
+    let team = opts.encounter.team();
+    if !team.is_possible() {
+        return vec![];
+    }
+
+    StateIterator::new(Xdrng::new(opts.seed))
+        .skip(opts.offset)
+        .enumerate()
+        .skip(opts.initial_advances)
+        .take(opts.max_advances.wrapping_add(1))
+        .filter_map(|(advance, rng)| {
+            let generated = generate_shadow(rng, &team, opts.tid, opts.sid);
+            let state = shadow_state(generated, &team, (opts.tid, opts.sid), rng.seed(), advance);
+
+            if opts.filter.pass_filter(&state) {
+                Some(state)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ShadowSearcherOptions {
+    pub encounter: ShadowEncounter,
+    pub tid: u16,
+    pub sid: u16,
+    pub filter: PkmFilter,
+}
+
+// Each IV rand holds three IVs in its low 15 bits, so only the top bit
+// and the low 16 bits of the first IV state are unknown
+fn recover_xd_iv_seeds(ivs: &Ivs) -> Vec<u32> {
+    let iv1 = ((ivs.def as u32) << 10) | ((ivs.atk as u32) << 5) | ivs.hp as u32;
+    let iv2 = ((ivs.spd as u16) << 10) | ((ivs.spa as u16) << 5) | ivs.spe as u16;
+
+    (0..(1u32 << 17))
+        .filter_map(|bits| {
+            let state = ((bits >> 16) << 31) | (iv1 << 16) | (bits & 0xffff);
+            let mut rng = Xdrng::new(state);
+            if rng.rand::<u16>() & 0x7fff == iv2 {
+                Xdrng::new(state).next_back()
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+// The locks consume a variable number of rands, so every nearby earlier seed
+// is generated forward to see if it lands on the IVs
+fn search_shadow(ivs: Ivs, team: &ShadowTeam, opts: &ShadowSearcherOptions) -> Vec<ShadowState> {
+    recover_xd_iv_seeds(&ivs)
+        .into_iter()
+        .flat_map(|iv_seed| {
+            Xdrng::new(iv_seed)
+                .rev()
+                .take(MAX_RANDS_BEFORE_SHADOW)
+                .filter_map(move |seed| {
+                    let generated = generate_shadow(Xdrng::new(seed), team, opts.tid, opts.sid);
+                    if generated.iv_seed != iv_seed {
+                        return None;
+                    }
+
+                    let state = shadow_state(generated, team, (opts.tid, opts.sid), seed, 0);
+                    if opts.filter.pass_filter_no_ivs(&state) {
+                        Some(state)
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<ShadowState>>()
+        })
+        .collect()
+}
+
+#[wasm_bindgen]
+pub fn gamecube_shadow_searcher_states(opts: &ShadowSearcherOptions) -> Vec<ShadowState> {
+    let team = opts.encounter.team();
+    if !team.is_possible() {
+        return vec![];
+    }
+    let Ivs {
+        hp: min_hp,
+        atk: min_atk,
+        def: min_def,
+        spa: min_spa,
+        spd: min_spd,
+        spe: min_spe,
+    } = opts.filter.min_ivs;
+
+    let Ivs {
+        hp: max_hp,
+        atk: max_atk,
+        def: max_def,
+        spa: max_spa,
+        spd: max_spd,
+        spe: max_spe,
+    } = opts.filter.max_ivs;
+
+    iproduct!(
+        min_hp..=max_hp,
+        min_atk..=max_atk,
+        min_def..=max_def,
+        min_spa..=max_spa,
+        min_spd..=max_spd,
+        min_spe..=max_spe
+    )
+    .flat_map(|(hp, atk, def, spa, spd, spe)| {
+        search_shadow(
+            Ivs {
+                hp,
+                atk,
+                def,
+                spa,
+                spd,
+                spe,
+            },
+            &team,
+            opts,
+        )
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Species;
+    use crate::generators::gen3::ShadowLock;
+
+    const EEVEE_LOCK: ShadowLock = ShadowLock {
+        species: Species::Eevee,
+        nature: Some(Nature::Hardy),
+        gender: Some(Gender::Female),
+    };
+
+    fn eevee_team() -> ShadowTeam {
+        ShadowTeam {
+            game: GamecubeGame::Xd,
+            shadow: Species::Teddiursa,
+            members: vec![ShadowTeamMember::Lock(EEVEE_LOCK)],
+        }
+    }
+
+    #[test]
+    fn generates_shadow_without_locks() {
+        let team = ShadowEncounter::XdTeddiursa.team();
+        let generated = generate_shadow(Xdrng::new(0x12345678), &team, 0, 0);
+
+        let mut rng = Xdrng::new(0x12345678);
+        rng.advance(2);
+        assert_eq!(generated.iv_seed, rng.seed());
+        let ivs = Ivs::new_g3(rng.rand::<u16>(), rng.rand::<u16>());
+        assert_eq!(generated.ivs, ivs);
+    }
+
+    #[test]
+    fn rerolls_locked_pids() {
+        let team = eevee_team();
+        let generated = generate_shadow(Xdrng::new(0xabcdef01), &team, 0, 0);
+
+        // Enemy IDs, the lock's IVs and ability, then every rolled PID
+        let mut rng = Xdrng::new(0xabcdef01);
+        rng.advance(5 + generated.rerolls * 2);
+        let lock_pid = rand_pid(&mut rng);
+        assert!(EEVEE_LOCK.pass(lock_pid));
+        assert_eq!(generated.iv_seed, rng.seed());
+    }
+
+    #[test]
+    fn rolls_caught_shadows_without_rerolls() {
+        let team = ShadowTeam {
+            members: vec![
+                ShadowTeamMember::CaughtShadow,
+                ShadowTeamMember::Lock(EEVEE_LOCK),
+            ],
+            ..eevee_team()
+        };
+        let generated = generate_shadow(Xdrng::new(0xabcdef01), &team, 0, 0);
+
+        // Enemy IDs, the caught shadow's IVs, ability and PID, then the lock as before
+        let mut rng = Xdrng::new(0xabcdef01);
+        rng.advance(7 + 3 + generated.rerolls * 2);
+        let lock_pid = rand_pid(&mut rng);
+        assert!(EEVEE_LOCK.pass(lock_pid));
+        assert_eq!(generated.iv_seed, rng.seed());
+    }
+
+    #[test]
+    fn is_never_shiny_for_player() {
+        let opts = ShadowGeneratorOptions {
+            seed: 0,
+            offset: 0,
+            initial_advances: 0,
+            max_advances: 20_000,
+            encounter: ShadowEncounter::Custom(eevee_team()),
+            tid: 12345,
+            sid: 54321,
+            filter: PkmFilter::new_allow_all(),
+        };
+        let states = gamecube_shadow_states(&opts);
+
+        assert_eq!(states.len(), 20_001);
+        assert!(
+            states
+                .iter()
+                .all(|state| !gen3_shiny(state.pid, opts.tid, opts.sid))
+        );
+    }
+
+    #[test]
+    fn searcher_finds_generated_shadow() {
+        let encounter = ShadowEncounter::Custom(eevee_team());
+        let team = encounter.team();
+        let expected = shadow_state(
+            generate_shadow(Xdrng::new(0xdeadbeef), &team, 12345, 54321),
+            &team,
+            (12345, 54321),
+            0xdeadbeef,
+            0,
+        );
+
+        let mut filter = PkmFilter::new_allow_all();
+        filter.min_ivs = expected.ivs;
+        filter.max_ivs = expected.ivs;
+        let results = gamecube_shadow_searcher_states(&ShadowSearcherOptions {
+            encounter,
+            tid: 12345,
+            sid: 54321,
+            filter,
+        });
+
+        assert!(results.contains(&expected));
+        assert!(results.iter().all(|state| state.ivs == expected.ivs));
+    }
+
+    #[test]
+    fn colosseum_only_checks_enemy_ids() {
+        let opts = ShadowGeneratorOptions {
+            seed: 0,
+            offset: 0,
+            initial_advances: 0,
+            max_advances: 100_000,
+            encounter: ShadowEncounter::Custom(ShadowTeam {
+                game: GamecubeGame::Colosseum,
+                ..eevee_team()
+            }),
+            tid: 12345,
+            sid: 54321,
+            filter: PkmFilter {
+                shiny: true,
+                ..PkmFilter::new_allow_all()
+            },
+        };
+        let states = gamecube_shadow_states(&opts);
+
+        assert!(!states.is_empty());
+        states.iter().for_each(|state| {
+            let mut rng = Xdrng::new(state.seed);
+            let (enemy_tid, enemy_sid) = (rng.rand::<u16>(), rng.rand::<u16>());
+            assert!(gen3_shiny(state.pid, opts.tid, opts.sid));
+            assert!(!gen3_shiny(state.pid, enemy_tid, enemy_sid));
+        });
+    }
+
+    #[test]
+    fn skips_impossible_locks() {
+        let team = ShadowTeam {
+            members: vec![ShadowTeamMember::Lock(ShadowLock {
+                species: Species::Magnemite,
+                nature: None,
+                gender: Some(Gender::Female),
+            })],
+            ..eevee_team()
+        };
+        let opts = ShadowGeneratorOptions {
+            seed: 0,
+            offset: 0,
+            initial_advances: 0,
+            max_advances: 10,
+            encounter: ShadowEncounter::Custom(team.clone()),
+            tid: 0,
+            sid: 0,
+            filter: PkmFilter::new_allow_all(),
+        };
+
+        assert!(!team.is_possible());
+        assert!(eevee_team().is_possible());
+        assert_eq!(gamecube_shadow_states(&opts), vec![]);
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{
+    AbilityType, Gender, Ivs, Nature, PkmFilter, PkmState, gen3_shiny,
+    rng::{Rng, StateIterator, lcrng::Xdrng},
+};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+pub const CHANNEL_TID: u16 = 40122;
+
+// SID, PID high, PID low, then three unused rands before the IVs
+const RANDS_BEFORE_IVS: usize = 6;
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ChannelJirachiState {
+    pub advance: usize,
+    pub seed: u32,
+    pub pid: u32,
+    pub sid: u16,
+    pub ivs: Ivs,
+}
+
+impl PkmState for ChannelJirachiState {
+    fn ivs(&self) -> &Ivs {
+        &self.ivs
+    }
+
+    fn shiny(&self) -> bool {
+        gen3_shiny(self.pid, CHANNEL_TID, self.sid)
+    }
+
+    fn nature(&self) -> Nature {
+        Nature::from_pid(self.pid)
+    }
+
+    fn ability(&self) -> AbilityType {
+        AbilityType::First
+    }
+
+    fn gender(&self) -> Gender {
+        Gender::Genderless
+    }
+}
+
+// Channel rolls the last three IVs in reverse: Spe, SpA, then SpD
+fn rand_channel_ivs(rng: &mut Xdrng) -> Ivs {
+    let mut rand_iv = || (rng.rand::<u16>() >> 11) as u8;
+    let hp = rand_iv();
+    let atk = rand_iv();
+    let def = rand_iv();
+    let spe = rand_iv();
+    let spa = rand_iv();
+    let spd = rand_iv();
+
+    Ivs {
+        hp,
+        atk,
+        def,
+        spa,
+        spd,
+        spe,
+    }
+}
+
+pub fn generate_channel_jirachi(mut rng: Xdrng, advance: usize) -> ChannelJirachiState {
+    let seed = rng.seed();
+    let sid = rng.rand::<u16>();
+    let mut high = rng.rand::<u16>();
+    let low = rng.rand::<u16>();
+    // Channel's anti-shiny check is broken, so it flips the PID almost every time
+    if (low <= 7) as u16 != high ^ CHANNEL_TID ^ sid {
+        high ^= 0x8000;
+    }
+
+    rng.advance(RANDS_BEFORE_IVS - 3);
+    let ivs = rand_channel_ivs(&mut rng);
+
+    ChannelJirachiState {
+        advance,
+        seed,
+        pid: ((high as u32) << 16) | low as u32,
+        sid,
+        ivs,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ChannelJirachiOptions {
+    pub seed: u32,
+    pub offset: usize,
+    pub initial_advances: usize,
+    pub max_advances: usize,
+    pub filter: PkmFilter,
+}
+
+#[wasm_bindgen]
+pub fn channel_jirachi_states(opts: &ChannelJirachiOptions) -> Vec<ChannelJirachiState> {
+    StateIterator::new(Xdrng::new(opts.seed))
+        .skip(opts.offset)
+        .enumerate()
+        .skip(opts.initial_advances)
+        .take(opts.max_advances.wrapping_add(1))
+        .filter_map(|(advance, rng)| {
+            let state = generate_channel_jirachi(rng, advance);
+
+            if opts.filter.pass_filter(&state) {
+                Some(state)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+// Each IV is the top five bits of its own rand, so HP fixes the top of the first IV state
+// and the other 27 bits are brute forced.
+#[wasm_bindgen]
+pub fn channel_jirachi_seeds(ivs: Ivs) -> Vec<ChannelJirachiState> {
+    let hp = (ivs.hp as u32 & 0x1f) << 27;
+    let rest = [ivs.atk, ivs.def, ivs.spe, ivs.spa, ivs.spd];
+
+    (0..(1u32 << 27))
+        .filter_map(|low_bits| {
+            let iv_state = hp | low_bits;
+            let mut rng = Xdrng::new(iv_state);
+            if rest
+                .into_iter()
+                .any(|iv| (rng.rand::<u16>() >> 11) as u8 != iv)
+            {
+                return None;
+            }
+
+            let seed = Xdrng::new(iv_state).rev().nth(RANDS_BEFORE_IVS)?;
+            Some(generate_channel_jirachi(Xdrng::new(seed), 0))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn generates_channel_jirachi() {
+        let state = generate_channel_jirachi(Xdrng::new(0x12345678), 0);
+
+        let mut rng = Xdrng::new(0x12345678);
+        assert_eq!(state.sid, rng.rand::<u16>());
+        rng.advance(5);
+        let rolls = (0..6)
+            .map(|_| (rng.rand::<u16>() >> 11) as u8)
+            .collect::<Vec<u8>>();
+        assert_eq!(
+            [
+                state.ivs.hp,
+                state.ivs.atk,
+                state.ivs.def,
+                state.ivs.spe,
+                state.ivs.spa,
+                state.ivs.spd
+            ],
+            rolls[..]
+        );
+    }
+
+    #[test]
+    fn generates_known_channel_jirachi() {
+        let state = generate_channel_jirachi(Xdrng::new(0x12345678), 0);
+
+        assert_eq!(state.sid, 46057);
+        assert_eq!(state.pid, 0xdb3fcc0d);
+        assert_eq!(state.nature(), Nature::Lax);
+        assert!(!state.shiny());
+        assert_eq!(
+            state.ivs,
+            Ivs {
+                hp: 3,
+                atk: 24,
+                def: 15,
+                spa: 27,
+                spd: 5,
+                spe: 6,
+            }
+        );
+    }
+
+    #[test]
+    fn flips_pid_unless_check_passes() {
+        let opts = ChannelJirachiOptions {
+            seed: 0,
+            offset: 0,
+            initial_advances: 0,
+            max_advances: 10_000,
+            filter: PkmFilter::new_allow_all(),
+        };
+        let states = channel_jirachi_states(&opts);
+
+        assert_eq!(states.len(), 10_001);
+        states.iter().for_each(|state| {
+            let mut rng = Xdrng::new(state.seed);
+            rng.advance(1);
+            let high = rng.rand::<u16>();
+            let low = rng.rand::<u16>();
+            let kept = (low <= 7) as u16 == high ^ CHANNEL_TID ^ state.sid;
+            let expected_high = if kept { high } else { high ^ 0x8000 };
+
+            assert_eq!(state.pid, ((expected_high as u32) << 16) | low as u32);
+        });
+    }
+
+    #[test]
+    fn recovers_seed_from_ivs() {
+        let expected = generate_channel_jirachi(Xdrng::new(0xdeadbeef), 0);
+        let seeds = channel_jirachi_seeds(expected.ivs);
+
+        assert!(seeds.contains(&expected));
+        assert!(seeds.iter().all(|state| state.ivs == expected.ivs));
+    }
+}
@@ -5,9 +5,22 @@ use tsify_next::Tsify;
 const BLOCK_ID_OFFSET: usize = 0xff4;
 const BLOCK_COUNT: usize = 14;
 const SIZE_BLOCK: usize = 0x1000;
+const BLOCK_CHECKSUM_OFFSET: usize = 0xff6;
 const BLOCK_MAGIC: u32 = 0x8012025;
 const SAVE_LEN_1: usize = 131_088;
 const SAVE_LEN_2: usize = 131_072;
+const SECTION_DATA_LEN: usize = 0xf80;
+
+const TRAINER_INFO_BLOCK: usize = 0;
+const TEAM_BLOCK: usize = 1;
+const FIRST_PC_BLOCK: usize = 5;
+const LAST_PC_BLOCK: usize = 13;
+
+const PARTY_SIZE: usize = 6;
+pub const PARTY_MON_LEN: usize = 100;
+pub const BOX_MON_LEN: usize = 80;
+pub const BOX_COUNT: usize = 14;
+pub const BOX_SLOTS: usize = 30;
 
 fn chk_u32(data: impl Iterator<Item = u32>) -> u32 {
     data.fold(0u32, u32::wrapping_add)
@@ -31,6 +44,25 @@ fn le_u32(bytes: &[u8]) -> u32 {
 pub enum Sav3ReadError {
     InvalidLength,
     InvalidMagic,
+    InvalidChecksum,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Gen3SaveVersion {
+    RubySapphire,
+    Emerald,
+    FireRedLeafGreen,
+}
+
+impl Gen3SaveVersion {
+    // Where the party count lives in the team block, followed by the party
+    fn party_offset(&self) -> usize {
+        match self {
+            Gen3SaveVersion::FireRedLeafGreen => 0x34,
+            _ => 0x234,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -59,7 +91,20 @@ impl<'a> Sav3<'a> {
             return Err(Sav3ReadError::InvalidMagic);
         }
 
-        Ok(Self { data })
+        let save = Self { data };
+        if !save.valid_slot(Self::SAVE_0_OFFSET) && !save.valid_slot(Self::SAVE_1_OFFSET) {
+            return Err(Sav3ReadError::InvalidChecksum);
+        }
+
+        Ok(save)
+    }
+
+    // A slot is only usable if every sector was fully written
+    fn valid_slot(&self, save_offset: usize) -> bool {
+        self.data[save_offset..][..SIZE_BLOCK * BLOCK_COUNT]
+            .chunks_exact(SIZE_BLOCK)
+            .map(Block::new)
+            .all(|block| block.valid_magic() && block.valid_checksum())
     }
 
     pub fn current_save_slot(&self) -> SaveSlot {
@@ -72,8 +117,8 @@ impl<'a> Sav3<'a> {
         let s1_save_count = s1.save_count();
 
         match (
-            s0.valid_magic(),
-            s1.valid_magic(),
+            self.valid_slot(Self::SAVE_0_OFFSET),
+            self.valid_slot(Self::SAVE_1_OFFSET),
             s0_save_count,
             s1_save_count,
         ) {
@@ -114,11 +159,79 @@ impl<'a> Sav3<'a> {
         (SIZE_BLOCK * block_index) + save_offset
     }
 
+    // Saves the current slot again with a new play time, like the game would.
+    // The new save goes into the other slot and becomes the newest one.
+    pub fn with_save_time(&self, save_time: &SaveTime) -> Vec<u8> {
+        let mut data = self.data.to_vec();
+        let current_offset = self.current_save_offset();
+        let next_offset = match self.current_save_slot() {
+            SaveSlot::Save0 => Self::SAVE_1_OFFSET,
+            SaveSlot::Save1 => Self::SAVE_0_OFFSET,
+        };
+        let save_count = self.block(TRAINER_INFO_BLOCK).save_count().wrapping_add(1);
+
+        let slot_len = SIZE_BLOCK * BLOCK_COUNT;
+        data.copy_within(current_offset..current_offset + slot_len, next_offset);
+        data[next_offset..][..slot_len]
+            .chunks_exact_mut(SIZE_BLOCK)
+            .for_each(|block| block[0xffc..].copy_from_slice(&save_count.to_le_bytes()));
+
+        let offset = self.block_offset(TRAINER_INFO_BLOCK) - current_offset + next_offset;
+        let block = &mut data[offset..][..SIZE_BLOCK];
+
+        block[0xe..][..2].copy_from_slice(&save_time.hours.to_le_bytes());
+        block[0x10] = save_time.minutes;
+        block[0x11] = save_time.seconds;
+        block[0x12] = save_time.frames;
+
+        let chk = Block::new(block).chk_u16();
+        block[BLOCK_CHECKSUM_OFFSET..][..2].copy_from_slice(&chk.to_le_bytes());
+        data
+    }
+
     pub fn block(&self, block_id: usize) -> Block<'a> {
         let offset = self.block_offset(block_id);
         let block = &self.data[offset..][..SIZE_BLOCK];
         Block::new(block)
     }
+
+    // RS leaves the game code empty, FRLG sets it to 1, and Emerald stores its security key there
+    pub fn version(&self) -> Gen3SaveVersion {
+        match le_u32(&self.block(TRAINER_INFO_BLOCK).data[0xac..][..4]) {
+            0 => Gen3SaveVersion::RubySapphire,
+            1 => Gen3SaveVersion::FireRedLeafGreen,
+            _ => Gen3SaveVersion::Emerald,
+        }
+    }
+
+    pub fn trainer_info(&self) -> Block<'a> {
+        self.block(TRAINER_INFO_BLOCK)
+    }
+
+    pub fn party(&self) -> Vec<&'a [u8]> {
+        let offset = self.version().party_offset();
+        let team = self.block(TEAM_BLOCK).data;
+        let count = le_u32(&team[offset..][..4]) as usize;
+        team[offset + 4..]
+            .chunks_exact(PARTY_MON_LEN)
+            .take(count.min(PARTY_SIZE))
+            .collect()
+    }
+
+    // The PC is split across several blocks, starting with the current box
+    pub fn pc_buffer(&self) -> Vec<u8> {
+        (FIRST_PC_BLOCK..=LAST_PC_BLOCK)
+            .flat_map(|block_id| self.block(block_id).data[..SECTION_DATA_LEN].to_vec())
+            .collect()
+    }
+
+    pub fn box_mons(&self) -> Vec<Vec<u8>> {
+        self.pc_buffer()[4..]
+            .chunks_exact(BOX_MON_LEN)
+            .take(BOX_COUNT * BOX_SLOTS)
+            .map(|mon| mon.to_vec())
+            .collect()
+    }
 }
 
 pub struct Block<'a> {
@@ -135,6 +248,11 @@ impl<'a> Block<'a> {
         magic == BLOCK_MAGIC
     }
 
+    // The game zeroes each sector before writing it, so the unused tail adds nothing
+    fn valid_checksum(&self) -> bool {
+        self.checksum() == self.chk_u16()
+    }
+
     fn save_count(&self) -> u32 {
         le_u32(&self.data[0xffc..][..4])
     }
@@ -143,6 +261,14 @@ impl<'a> Block<'a> {
         chk_u32(self.data.chunks_exact(4).take(0xf80 / 4).map(le_u32))
     }
 
+    pub fn chk_u16(&self) -> u16 {
+        chk_u16(self.data.chunks_exact(4).take(0xf80 / 4).map(le_u32))
+    }
+
+    pub fn checksum(&self) -> u16 {
+        le_u16(&self.data[BLOCK_CHECKSUM_OFFSET..][..2])
+    }
+
     pub fn save_time(&self) -> SaveTime {
         SaveTime {
             hours: le_u16(&self.data[0xe..][..2]),
@@ -152,6 +278,35 @@ impl<'a> Block<'a> {
         }
     }
 
+    pub fn trainer_name(&self) -> &'a [u8] {
+        &self.data[..7]
+    }
+
+    pub fn trainer_id(&self) -> (u16, u16) {
+        (
+            le_u16(&self.data[0xa..][..2]),
+            le_u16(&self.data[0xc..][..2]),
+        )
+    }
+
+    // Days, hours, minutes and seconds added to the cartridge clock
+    pub fn rtc_offset(&self) -> (i16, i8, i8, i8) {
+        Self::rtc_time(&self.data[0x98..][..5])
+    }
+
+    pub fn last_berry_update(&self) -> (i16, i8, i8, i8) {
+        Self::rtc_time(&self.data[0xa0..][..5])
+    }
+
+    fn rtc_time(bytes: &[u8]) -> (i16, i8, i8, i8) {
+        (
+            le_u16(&bytes[..2]) as i16,
+            bytes[2] as i8,
+            bytes[3] as i8,
+            bytes[4] as i8,
+        )
+    }
+
     pub fn time_words(&self, save_time: &SaveTime) -> [u32; 2] {
         let hour_bytes = save_time.hours.to_le_bytes();
         [
@@ -166,6 +321,42 @@ impl<'a> Block<'a> {
     }
 }
 
+// Builds saves for tests in other modules
+#[cfg(test)]
+pub mod test_saves {
+    use super::*;
+
+    // Block ids, the magic and the save count for every block in a slot
+    pub fn write_slot_footers(data: &mut [u8], slot: usize, save_count: u32) {
+        for block_id in 0..BLOCK_COUNT {
+            let block = &mut data[(slot * BLOCK_COUNT + block_id) * SIZE_BLOCK..][..SIZE_BLOCK];
+            block[BLOCK_ID_OFFSET] = block_id as u8;
+            block[0xff8..0xffc].copy_from_slice(&BLOCK_MAGIC.to_le_bytes());
+            block[0xffc..].copy_from_slice(&save_count.to_le_bytes());
+        }
+    }
+
+    // Run after the block data is written
+    pub fn write_checksums(data: &mut [u8]) {
+        for block in data.chunks_exact_mut(SIZE_BLOCK) {
+            let chk = Block::new(block).chk_u16();
+            block[BLOCK_CHECKSUM_OFFSET..][..2].copy_from_slice(&chk.to_le_bytes());
+        }
+    }
+
+    pub fn save_with_newer_slot_1() -> Vec<u8> {
+        let mut data = vec![0; SAVE_LEN_2];
+        for (slot, save_count) in [(0, 1), (1, 2)] {
+            write_slot_footers(&mut data, slot, save_count);
+        }
+        for block in data.chunks_exact_mut(SIZE_BLOCK) {
+            block[0xf8..0xfc].fill(0x11);
+        }
+        write_checksums(&mut data);
+        data
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -211,4 +402,56 @@ mod test {
             assert_eq!(time_words[1], 0xaa070605);
         }
     }
+
+    mod sav3 {
+        use super::*;
+        use crate::gen3::jirachi::gba_save::test_saves::save_with_newer_slot_1;
+
+        const SAVE_1_START: usize = BLOCK_COUNT * SIZE_BLOCK;
+
+        #[test]
+        fn with_save_time() {
+            let data = save_with_newer_slot_1();
+            let save_time = SaveTime {
+                hours: 12,
+                minutes: 34,
+                seconds: 56,
+                frames: 7,
+            };
+            let rewritten = Sav3::new(&data).unwrap().with_save_time(&save_time);
+            let save = Sav3::new(&rewritten).unwrap();
+            let block0 = save.block(0);
+
+            // Slot 1 is copied into slot 0, which becomes the newest save
+            assert_eq!(save.current_save_slot(), SaveSlot::Save0);
+            assert_eq!(block0.save_count(), 3);
+            assert_eq!(block0.save_time(), save_time);
+            assert_eq!(block0.checksum(), block0.chk_u16());
+            assert_eq!(
+                rewritten[SIZE_BLOCK..][..SECTION_DATA_LEN],
+                data[SAVE_1_START + SIZE_BLOCK..][..SECTION_DATA_LEN]
+            );
+            assert_eq!(rewritten[SAVE_1_START..], data[SAVE_1_START..]);
+        }
+
+        #[test]
+        fn falls_back_to_slot_with_valid_checksums() {
+            let mut data = save_with_newer_slot_1();
+            // A save interrupted while writing slot 1
+            data[SAVE_1_START + 3 * SIZE_BLOCK] ^= 1;
+            let save = Sav3::new(&data).unwrap();
+
+            assert_eq!(save.current_save_slot(), SaveSlot::Save0);
+            assert_eq!(save.block(0).save_count(), 1);
+        }
+
+        #[test]
+        fn rejects_save_without_valid_slot() {
+            let mut data = save_with_newer_slot_1();
+            data[0] ^= 1;
+            data[SAVE_1_START] ^= 1;
+
+            assert_eq!(Sav3::new(&data).err(), Some(Sav3ReadError::InvalidChecksum));
+        }
+    }
 }
@@ -1,4 +1,5 @@
-mod gba_save;
+pub mod channel;
+pub mod gba_save;
 mod generator;
 mod save_time;
 pub mod searcher;
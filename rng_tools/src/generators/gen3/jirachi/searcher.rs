@@ -21,6 +21,7 @@ pub struct MultibootJirachiSaveSpread {
 pub enum JirachiSaveError {
     InvalidSave(Sav3ReadError),
     NeedToSaveAgain,
+    SpreadNotFromSave,
 }
 
 pub type JirachiSaveResult = Result<Vec<MultibootJirachiSaveSpread>, JirachiSaveError>;
@@ -81,3 +82,87 @@ pub fn search_mb_jirachi_times(opts: MultibootJirachiOptions) -> JirachiSaveResu
 
     Ok(results)
 }
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct MultibootJirachiRewriteOptions {
+    pub save: Vec<u8>,
+    pub spread: MultibootJirachiSaveSpread,
+}
+
+// For emulator users who would rather edit the save than wait for the right play time
+#[wasm_bindgen]
+pub fn rewrite_mb_jirachi_save(
+    opts: MultibootJirachiRewriteOptions,
+) -> Result<Vec<u8>, JirachiSaveError> {
+    let save = Sav3::new(&opts.save).map_err(JirachiSaveError::InvalidSave)?;
+
+    // The same slot search_mb_jirachi_times reads from
+    if save.current_save_slot() == SaveSlot::Save0 {
+        return Err(JirachiSaveError::NeedToSaveAgain);
+    }
+
+    // Saving again writes slot 0, which is the block 0 Wishmaker/Meteor reads
+    let rewritten = save.with_save_time(&opts.spread.save_time);
+    let block0 = Sav3::new(&rewritten)
+        .map_err(JirachiSaveError::InvalidSave)?
+        .block(0);
+
+    // The spread was found with a different save
+    if block0.checksum() != opts.spread.seed {
+        return Err(JirachiSaveError::SpreadNotFromSave);
+    }
+
+    Ok(rewritten)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gen3::jirachi::gba_save::test_saves::save_with_newer_slot_1;
+
+    #[test]
+    fn rewrites_save_to_hit_spread() {
+        let save = save_with_newer_slot_1();
+        let spreads = search_mb_jirachi_times(MultibootJirachiOptions {
+            save: save.clone(),
+            hours: 1,
+            jirachi_type: MultibootJirachiType::Wishmaker,
+            filter: PkmFilter::new_allow_all(),
+        })
+        .unwrap();
+
+        for spread in spreads.into_iter().step_by(1_000) {
+            let rewritten = rewrite_mb_jirachi_save(MultibootJirachiRewriteOptions {
+                save: save.clone(),
+                spread: spread.clone(),
+            })
+            .unwrap();
+            let rewritten_save = Sav3::new(&rewritten).unwrap();
+            let block0 = rewritten_save.block(0);
+
+            assert_eq!(rewritten_save.current_save_slot(), SaveSlot::Save0);
+            assert_eq!(block0.save_time(), spread.save_time);
+            assert_eq!(block0.checksum(), spread.seed);
+        }
+    }
+
+    #[test]
+    fn rejects_spread_from_other_save() {
+        let save = save_with_newer_slot_1();
+        let mut spread = search_mb_jirachi_times(MultibootJirachiOptions {
+            save: save.clone(),
+            hours: 1,
+            jirachi_type: MultibootJirachiType::Meteor,
+            filter: PkmFilter::new_allow_all(),
+        })
+        .unwrap()
+        .remove(0);
+        spread.seed = spread.seed.wrapping_add(1);
+
+        assert_eq!(
+            rewrite_mb_jirachi_save(MultibootJirachiRewriteOptions { save, spread }),
+            Err(JirachiSaveError::SpreadNotFromSave)
+        );
+    }
+}
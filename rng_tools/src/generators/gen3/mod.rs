@@ -1,8 +1,14 @@
 mod egg;
 mod feebas_sid;
+mod feebas_tiles;
+mod game;
+mod gamecube;
 mod jirachi;
 mod mirage_island;
+mod pickup;
 mod pokerus;
+mod roamer;
+mod save;
 mod seed;
 mod stationary;
 mod tidsid;
@@ -12,9 +18,15 @@ mod wild;
 
 pub use egg::*;
 pub use feebas_sid::*;
+pub use feebas_tiles::*;
+pub use game::*;
+pub use gamecube::*;
 pub use jirachi::*;
 pub use mirage_island::*;
+pub use pickup::*;
 pub use pokerus::*;
+pub use roamer::*;
+pub use save::*;
 pub use seed::*;
 pub use stationary::*;
 pub use tidsid::*;
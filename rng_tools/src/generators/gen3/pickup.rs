@@ -0,0 +1,290 @@
+use super::Gen3Game;
+use crate::rng::lcrng::Pokerng;
+use crate::rng::{Rng, StateIterator};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Gen3PickupItem {
+    Potion,
+    Antidote,
+    SuperPotion,
+    GreatBall,
+    Repel,
+    EscapeRope,
+    XAttack,
+    FullHeal,
+    UltraBall,
+    HyperPotion,
+    RareCandy,
+    Protein,
+    Revive,
+    HpUp,
+    FullRestore,
+    MaxRevive,
+    PpUp,
+    MaxElixir,
+    Nugget,
+    KingsRock,
+    Ether,
+    WhiteHerb,
+    Tm44Rest,
+    Elixir,
+    Tm01FocusPunch,
+    Leftovers,
+    Tm26Earthquake,
+    OranBerry,
+    CheriBerry,
+    ChestoBerry,
+    PechaBerry,
+    RawstBerry,
+    AspearBerry,
+    PersimBerry,
+    Tm10HiddenPower,
+    SpelonBerry,
+    PamtreBerry,
+    WatmelBerry,
+    DurinBerry,
+    BelueBerry,
+}
+
+use Gen3PickupItem::*;
+
+// Cumulative chances out of 100
+const RS_PICKUP_ITEMS: [(u16, Gen3PickupItem); 10] = [
+    (30, SuperPotion),
+    (40, FullHeal),
+    (50, UltraBall),
+    (60, RareCandy),
+    (70, FullRestore),
+    (80, Revive),
+    (90, Nugget),
+    (95, Protein),
+    (99, PpUp),
+    (100, KingsRock),
+];
+
+const FRLG_PICKUP_ITEMS: [(u16, Gen3PickupItem); 16] = [
+    (15, OranBerry),
+    (25, CheriBerry),
+    (35, ChestoBerry),
+    (45, PechaBerry),
+    (55, RawstBerry),
+    (65, AspearBerry),
+    (75, PersimBerry),
+    (80, Tm10HiddenPower),
+    (85, PpUp),
+    (90, RareCandy),
+    (95, Nugget),
+    (96, SpelonBerry),
+    (97, PamtreBerry),
+    (98, WatmelBerry),
+    (99, DurinBerry),
+    (100, BelueBerry),
+];
+
+// Emerald shifts both lists by one for every 10 levels
+const EMERALD_PICKUP_CHANCES: [u16; 9] = [30, 40, 50, 60, 70, 80, 90, 94, 98];
+
+const EMERALD_PICKUP_ITEMS: [Gen3PickupItem; 18] = [
+    Potion,
+    Antidote,
+    SuperPotion,
+    GreatBall,
+    Repel,
+    EscapeRope,
+    XAttack,
+    FullHeal,
+    UltraBall,
+    HyperPotion,
+    RareCandy,
+    Protein,
+    Revive,
+    HpUp,
+    FullRestore,
+    MaxRevive,
+    PpUp,
+    MaxElixir,
+];
+
+const EMERALD_RARE_PICKUP_ITEMS: [Gen3PickupItem; 11] = [
+    HyperPotion,
+    Nugget,
+    KingsRock,
+    FullRestore,
+    Ether,
+    WhiteHerb,
+    Tm44Rest,
+    Elixir,
+    Tm01FocusPunch,
+    Leftovers,
+    Tm26Earthquake,
+];
+
+fn cumulative_pickup_item(items: &[(u16, Gen3PickupItem)], rand: u16) -> Gen3PickupItem {
+    items
+        .iter()
+        .find(|(chance, _)| *chance > rand)
+        .map(|(_, item)| *item)
+        .unwrap_or(items[items.len() - 1].1)
+}
+
+// Rolls of 98 and 99 come from the rare list
+fn emerald_pickup_item(level: u8, rand: u16) -> Gen3PickupItem {
+    let level_offset = (level.saturating_sub(1) / 10).min(9) as usize;
+    if rand >= 98 {
+        return EMERALD_RARE_PICKUP_ITEMS[level_offset + (99 - rand) as usize];
+    }
+
+    let index = EMERALD_PICKUP_CHANCES
+        .iter()
+        .position(|chance| *chance > rand)
+        .unwrap_or_default();
+    EMERALD_PICKUP_ITEMS[level_offset + index]
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen3PickupOptions {
+    pub game: Gen3Game,
+    pub seed: u32,
+    pub initial_advances: usize,
+    pub max_advances: usize,
+    // Levels of the party Pokémon with Pickup and no held item, in party order
+    pub levels: Vec<u8>,
+    // Shows advances where any slot finds one of these, or every advance when empty
+    pub wanted_items: Vec<Gen3PickupItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen3PickupState {
+    pub advance: usize,
+    pub items: Vec<Option<Gen3PickupItem>>,
+}
+
+// Rolls the item for one Pokémon with Pickup, if it finds one
+pub fn rand_pickup_item(rng: &mut Pokerng, game: Gen3Game, level: u8) -> Option<Gen3PickupItem> {
+    if rng.rand_max::<u16>(10) != 0 {
+        return None;
+    }
+
+    let rand = rng.rand_max::<u16>(100);
+    let item = match game {
+        Gen3Game::Emerald => emerald_pickup_item(level, rand),
+        game if game.is_frlg() => cumulative_pickup_item(&FRLG_PICKUP_ITEMS, rand),
+        _ => cumulative_pickup_item(&RS_PICKUP_ITEMS, rand),
+    };
+    Some(item)
+}
+
+fn generate_pickup_items(
+    mut rng: Pokerng,
+    opts: &Gen3PickupOptions,
+) -> Vec<Option<Gen3PickupItem>> {
+    opts.levels
+        .iter()
+        .map(|&level| rand_pickup_item(&mut rng, opts.game, level))
+        .collect()
+}
+
+#[wasm_bindgen]
+pub fn gen3_pickup_states(opts: &Gen3PickupOptions) -> Vec<Gen3PickupState> {
+    StateIterator::new(Pokerng::new(opts.seed))
+        .enumerate()
+        .skip(opts.initial_advances)
+        .take(opts.max_advances.saturating_add(1))
+        .filter_map(|(advance, rng)| {
+            let items = generate_pickup_items(rng, opts);
+            let found_wanted_item = opts.wanted_items.is_empty()
+                || items
+                    .iter()
+                    .flatten()
+                    .any(|item| opts.wanted_items.contains(item));
+
+            if found_wanted_item {
+                Some(Gen3PickupState { advance, items })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn emerald_items_shift_with_level() {
+        assert_eq!(emerald_pickup_item(1, 0), Potion);
+        assert_eq!(emerald_pickup_item(10, 29), Potion);
+        assert_eq!(emerald_pickup_item(11, 29), Antidote);
+        assert_eq!(emerald_pickup_item(100, 0), HyperPotion);
+        assert_eq!(emerald_pickup_item(100, 97), MaxElixir);
+        assert_eq!(emerald_pickup_item(45, 85), RareCandy);
+        assert_eq!(emerald_pickup_item(5, 99), HyperPotion);
+        assert_eq!(emerald_pickup_item(5, 98), Nugget);
+        assert_eq!(emerald_pickup_item(100, 99), Leftovers);
+        assert_eq!(emerald_pickup_item(100, 98), Tm26Earthquake);
+    }
+
+    #[test]
+    fn rs_and_frlg_items() {
+        assert_eq!(cumulative_pickup_item(&RS_PICKUP_ITEMS, 55), RareCandy);
+        assert_eq!(cumulative_pickup_item(&RS_PICKUP_ITEMS, 98), PpUp);
+        assert_eq!(cumulative_pickup_item(&RS_PICKUP_ITEMS, 99), KingsRock);
+        assert_eq!(cumulative_pickup_item(&FRLG_PICKUP_ITEMS, 0), OranBerry);
+        assert_eq!(cumulative_pickup_item(&FRLG_PICKUP_ITEMS, 87), RareCandy);
+        assert_eq!(cumulative_pickup_item(&FRLG_PICKUP_ITEMS, 99), BelueBerry);
+    }
+
+    #[test]
+    fn finds_wanted_items() {
+        let opts = Gen3PickupOptions {
+            game: Gen3Game::Emerald,
+            seed: 0,
+            initial_advances: 0,
+            max_advances: 5000,
+            levels: vec![50, 50, 50, 50, 50, 50],
+            wanted_items: vec![RareCandy, PpUp],
+        };
+        let results = gen3_pickup_states(&opts);
+
+        assert!(!results.is_empty());
+        results.iter().for_each(|state| {
+            assert_eq!(state.items.len(), 6);
+            assert!(
+                state
+                    .items
+                    .iter()
+                    .any(|item| matches!(item, Some(RareCandy | PpUp)))
+            );
+
+            let mut rng = Pokerng::new(0);
+            rng.advance(state.advance);
+            assert_eq!(state.items, generate_pickup_items(rng, &opts));
+        });
+    }
+
+    #[test]
+    fn matches_pokerus_pickup_rolls() {
+        // The Pokérus generator rolls pickup the same way for Ruby & Sapphire
+        let opts = Gen3PickupOptions {
+            game: Gen3Game::Ruby,
+            seed: 0x5a0,
+            initial_advances: 10022,
+            max_advances: 0,
+            levels: vec![5; 5],
+            wanted_items: vec![],
+        };
+        let results = gen3_pickup_states(&opts);
+
+        assert_eq!(
+            results[0].items,
+            vec![None, None, None, None, Some(FullRestore)]
+        );
+    }
+}
@@ -1,99 +1,130 @@
+use super::{Gen3Game, Gen3PickupItem, RsTidSidOptions, rand_pickup_item};
 use crate::rng::lcrng::Pokerng;
 use crate::rng::{Rng, StateIterator};
+use crate::{Console, TargetAdvanceCandidate, TargetHitProbabilityOptions, rank_target_advances};
 use serde::{Deserialize, Serialize};
 use tsify_next::Tsify;
 use wasm_bindgen::prelude::*;
 
-// Pokérus generator and searcher for Ruby & Sapphire with dead battery
-use num_enum::{FromPrimitive, IntoPrimitive};
-
-#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize, FromPrimitive, IntoPrimitive)]
-#[repr(i8)]
-pub enum PickUpItem {
-    #[default]
-    None = -1,
-    SuperPotion = 0,
-    FullHeal = 1,
-    UltraBall = 2,
-    RareCandy = 3,
-    FullRestore = 4,
-    Revive = 5,
-    Nugget = 6,
-    Protein = 7,
-    PpUp = 8,
-    KingsRock = 9,
+// Pokérus generator and searcher for Ruby & Sapphire, Emerald and FRLG
+
+// vblanks between pickup and TV shows are either 4 (~80% of the time) or 6 (~20%)
+const PICKUP_VBLANK_OUTCOMES: [(usize, f32); 2] = [(4, 0.8), (6, 0.2)];
+const TV_VBLANKS_BEFORE_POKERUS: usize = 74;
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Pokerus3VersionOptions {
+    // Dead battery, live battery date time, or a known seed
+    Rs(RsTidSidOptions),
+    Emerald,
+    // The initial seed from the title screen
+    Frlg(u16),
 }
 
-const RS_SEED: u32 = 0x5A0; // Ruby & Sapphire dead battery
-const PICKUP_ITEM_CHANCE: [u16; 10] = [30, 40, 50, 60, 70, 80, 90, 95, 99, 0xFFFF];
+impl Pokerus3VersionOptions {
+    fn seed(&self) -> u32 {
+        match self {
+            Pokerus3VersionOptions::Rs(rs_opts) => rs_opts.seed() as u32,
+            Pokerus3VersionOptions::Emerald => 0,
+            Pokerus3VersionOptions::Frlg(seed) => *seed as u32,
+        }
+    }
+
+    // The pickup tables only differ between Ruby & Sapphire, Emerald and FRLG
+    fn game(&self) -> Gen3Game {
+        match self {
+            Pokerus3VersionOptions::Rs(_) => Gen3Game::Ruby,
+            Pokerus3VersionOptions::Emerald => Gen3Game::Emerald,
+            Pokerus3VersionOptions::Frlg(_) => Gen3Game::FireRed,
+        }
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct Pokerus3GeneratorOptions {
+    pub version_options: Pokerus3VersionOptions,
     pub initial_advance_before_pickup: usize,
     pub max_advances: usize,
     pub entered_hall_of_fame: bool,
     pub can_have_new_mass_outbreak: bool,
     pub has_empty_pokenews_slot: bool,
     pub level_up: bool,
-    pub pickup_pokemon_count: usize,
-    pub filter_pickup_items: Option<Vec<PickUpItem>>,
+    // Levels of the Pokémon with Pickup, in party order.
+    // Only Emerald's items depend on the level.
+    pub pickup_levels: Vec<u8>,
+    pub filter_pickup_items: Option<Vec<Option<Gen3PickupItem>>>,
+    // Passes if any vblank outcome matches
     pub filter_gives_pokerus: Option<bool>,
 }
 
 #[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
-pub struct Pokerus3GeneratorResult {
-    pub advance_before_pickup: usize,
-    pub pickup_items: Vec<PickUpItem>,
+pub struct Pokerus3Outcome {
+    pub vblanks: usize,
+    pub weight: f32,
     pub advance_before_pokerus: usize,
     pub gives_pokerus: bool,
 }
 
-fn generate_gen3_pokerus_state(
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Pokerus3GeneratorResult {
+    pub advance_before_pickup: usize,
+    pub pickup_items: Vec<Option<Gen3PickupItem>>,
+    // The most likely outcome comes first
+    pub outcomes: Vec<Pokerus3Outcome>,
+    pub pokerus_chance: f32,
+}
+
+fn generate_pokerus_outcome(
     mut rng: Pokerng,
     opts: &Pokerus3GeneratorOptions,
-    advance_before_pickup: usize,
-) -> Pokerus3GeneratorResult {
-    let mut advance_before_pokerus = advance_before_pickup;
+    advance_after_pickup: usize,
+    (vblanks, weight): (usize, f32),
+) -> Pokerus3Outcome {
+    let mut advance_before_pokerus = advance_after_pickup;
     let mut rand = || -> u16 {
         advance_before_pokerus += 1;
         rng.rand()
     };
 
-    let pickup_items: Vec<PickUpItem> = (0..opts.pickup_pokemon_count)
-        .map(|_| {
-            if rand() % 10 == 0 {
-                let chance = rand() % 100;
-
-                for (i, item) in PICKUP_ITEM_CHANCE.iter().enumerate() {
-                    if chance < *item {
-                        return (i as i8).into();
-                    }
-                }
-                PickUpItem::None // Error
-            } else {
-                PickUpItem::None
-            }
-        })
-        .collect();
-    // vblanks between pickup and TV shows. Either 4 (~80% of the time) or 6 (~20%). The tool assumes 4.
-    for _ in 0..4 {
+    for _ in 0..vblanks {
         rand();
     }
 
-    if opts.entered_hall_of_fame {
-        if opts.has_empty_pokenews_slot {
-            rand();
+    match opts.version_options {
+        Pokerus3VersionOptions::Rs(_) => {
+            if opts.entered_hall_of_fame {
+                if opts.has_empty_pokenews_slot {
+                    rand();
+                }
+                if opts.can_have_new_mass_outbreak && rand() <= 0x147 {
+                    rand();
+                }
+            }
+            rand(); // TV pokenew for not catching the Pokémon
         }
-        if opts.can_have_new_mass_outbreak && rand() <= 0x147 {
-            rand();
+        // Emerald rolls PokéNews and outbreaks as 1 in 100 and 1 in 200 chances,
+        // and only picks the kind or species when they happen
+        Pokerus3VersionOptions::Emerald => {
+            if opts.entered_hall_of_fame {
+                if opts.has_empty_pokenews_slot && rand() % 100 == 0 {
+                    rand();
+                }
+                if opts.can_have_new_mass_outbreak && rand() % 200 == 0 {
+                    rand();
+                }
+            }
+            rand(); // TV pokenew for not catching the Pokémon
         }
+        // FRLG has no TV shows, PokéNews or mass outbreaks
+        Pokerus3VersionOptions::Frlg(_) => {}
     }
-    rand(); // TV pokenew for not catching the Pokémon
 
     // vblanks between TV shows and Pokerus
-    for _ in 0..74 {
+    for _ in 0..TV_VBLANKS_BEFORE_POKERUS {
         rand();
     }
 
@@ -105,11 +136,47 @@ fn generate_gen3_pokerus_state(
 
     let pokerus_rng = rng.rand::<u16>();
     let gives_pokerus = pokerus_rng == 0x4000 || pokerus_rng == 0x8000 || pokerus_rng == 0xC000;
+    Pokerus3Outcome {
+        vblanks,
+        weight,
+        advance_before_pokerus,
+        gives_pokerus,
+    }
+}
+
+fn generate_gen3_pokerus_state(
+    mut rng: Pokerng,
+    opts: &Pokerus3GeneratorOptions,
+    advance_before_pickup: usize,
+) -> Pokerus3GeneratorResult {
+    let game = opts.version_options.game();
+    let pickup_items: Vec<Option<Gen3PickupItem>> = opts
+        .pickup_levels
+        .iter()
+        .map(|&level| rand_pickup_item(&mut rng, game, level))
+        .collect();
+    // One rand to find an item, and one more to pick it
+    let advance_after_pickup = advance_before_pickup
+        + pickup_items
+            .iter()
+            .map(|item| 1 + item.is_some() as usize)
+            .sum::<usize>();
+
+    let outcomes: Vec<Pokerus3Outcome> = PICKUP_VBLANK_OUTCOMES
+        .into_iter()
+        .map(|outcome| generate_pokerus_outcome(rng, opts, advance_after_pickup, outcome))
+        .collect();
+    let pokerus_chance = outcomes
+        .iter()
+        .filter(|outcome| outcome.gives_pokerus)
+        .map(|outcome| outcome.weight)
+        .sum();
+
     Pokerus3GeneratorResult {
         advance_before_pickup,
         pickup_items,
-        advance_before_pokerus,
-        gives_pokerus,
+        outcomes,
+        pokerus_chance,
     }
 }
 
@@ -124,6 +191,7 @@ pub fn get_target_advances_before_pickup(
     // The pokerus target advance is either near 26923, near 101199 or near 101236.
     // Try advance 26923 first, and if it is impossible to hit it, try 101199/101236.
     let mut opts = Pokerus3GeneratorOptions {
+        version_options: Pokerus3VersionOptions::Rs(RsTidSidOptions::DeadBattery),
         initial_advance_before_pickup: 26000,
         max_advances: 1000,
         entered_hall_of_fame,
@@ -131,49 +199,42 @@ pub fn get_target_advances_before_pickup(
         has_empty_pokenews_slot,
         level_up,
         filter_pickup_items: None,
-        pickup_pokemon_count,
+        // Ruby and Sapphire items don't depend on the level
+        pickup_levels: vec![1; pickup_pokemon_count],
         filter_gives_pokerus: Some(true),
     };
-    let mut results: Vec<Pokerus3GeneratorResult> = gen3_pokerus_generator_states(&opts);
-    if results.is_empty() {
+    let mut results = gen3_pokerus_generator_states(&opts);
+    // Only use the later targets when the usual 4 vblanks can't give Pokérus early
+    if !results
+        .iter()
+        .any(|result| result.outcomes[0].gives_pokerus)
+    {
         opts.initial_advance_before_pickup = 101000;
         results = gen3_pokerus_generator_states(&opts);
     }
-    let mapped_results: Vec<usize> = results.iter().map(|r| r.advance_before_pickup).collect();
-
-    // Find the longest consecutive advances. Ex: {1000, 1008, 1009} returns {1008, 1009}
-    match mapped_results.len() {
-        0 | 1 => mapped_results,
-        2 => {
-            let consecutive_1st_2nd = mapped_results[0] + 1 == mapped_results[1];
-            if consecutive_1st_2nd {
-                mapped_results
-            } else {
-                mapped_results[0..=0].to_vec()
-            }
-        }
-        3 => {
-            let consecutive_1st_2nd = mapped_results[0] + 1 == mapped_results[1];
-            let consecutive_2nd_3rd = mapped_results[1] + 1 == mapped_results[2];
-            if consecutive_1st_2nd && consecutive_2nd_3rd {
-                mapped_results
-            } else if consecutive_1st_2nd {
-                mapped_results[0..=1].to_vec()
-            } else if consecutive_2nd_3rd {
-                mapped_results[1..=2].to_vec()
-            } else {
-                mapped_results[0..=0].to_vec()
-            }
-        }
-        _ => mapped_results[0..=0].to_vec(), // no supported, but doesn't occur
-    }
+
+    // Easiest to hit first
+    let candidates = results
+        .iter()
+        .map(|result| TargetAdvanceCandidate {
+            advance: result.advance_before_pickup,
+            success_chance: result.pokerus_chance as f64,
+        })
+        .collect();
+    rank_target_advances(TargetHitProbabilityOptions {
+        console: Console::Gba,
+        candidates,
+    })
+    .into_iter()
+    .map(|target| target.advance)
+    .collect()
 }
 
 #[wasm_bindgen]
 pub fn gen3_pokerus_generator_states(
     opts: &Pokerus3GeneratorOptions,
 ) -> Vec<Pokerus3GeneratorResult> {
-    StateIterator::new(Pokerng::new(RS_SEED))
+    StateIterator::new(Pokerng::new(opts.version_options.seed()))
         .enumerate()
         .skip(opts.initial_advance_before_pickup)
         .take(opts.max_advances.saturating_add(1))
@@ -187,7 +248,11 @@ pub fn gen3_pokerus_generator_states(
             }
 
             if let Some(filter_gives_pokerus) = opts.filter_gives_pokerus {
-                if filter_gives_pokerus != state.gives_pokerus {
+                if !state
+                    .outcomes
+                    .iter()
+                    .any(|outcome| outcome.gives_pokerus == filter_gives_pokerus)
+                {
                     return None;
                 }
             }
@@ -201,6 +266,27 @@ pub fn gen3_pokerus_generator_states(
 mod tests {
     use super::*;
     use crate::assert_list_eq;
+    use crate::gen3::{Gen3PickupOptions, gen3_pickup_states};
+
+    fn rs_outcomes(
+        advance_before_pokerus: usize,
+        gives_pokerus: [bool; 2],
+    ) -> Vec<Pokerus3Outcome> {
+        vec![
+            Pokerus3Outcome {
+                vblanks: 4,
+                weight: 0.8,
+                advance_before_pokerus,
+                gives_pokerus: gives_pokerus[0],
+            },
+            Pokerus3Outcome {
+                vblanks: 6,
+                weight: 0.2,
+                advance_before_pokerus: advance_before_pokerus + 2,
+                gives_pokerus: gives_pokerus[1],
+            },
+        ]
+    }
 
     #[test]
     fn test_get_target_advance_before_pickup() {
@@ -236,12 +322,12 @@ mod tests {
             true,
             false,
             &[
-                vec![26842],
-                vec![101117],
-                vec![101152, 101153],
-                vec![101115],
-                vec![101114],
-                vec![101113],
+                vec![26842, 26840],
+                vec![101117, 101154, 101115, 101151],
+                vec![101152, 101153, 101150, 101116, 101114],
+                vec![101115, 101151, 101113, 101149],
+                vec![101114, 101150, 101112, 101148],
+                vec![101113, 101149, 101111, 101147],
             ],
         );
 
@@ -251,7 +337,7 @@ mod tests {
             false,
             false,
             &[
-                vec![26844],
+                vec![26844, 26842],
                 vec![26843],
                 vec![26841, 26842],
                 vec![26840],
@@ -266,12 +352,12 @@ mod tests {
             true,
             false,
             &[
-                vec![26843],
-                vec![26841, 26842],
-                vec![26840],
-                vec![26839],
-                vec![26838],
-                vec![26837],
+                vec![26843, 26841],
+                vec![26841, 26842, 26840],
+                vec![26840, 26839],
+                vec![26839, 26838],
+                vec![26838, 26837],
+                vec![26837, 26836],
             ],
         );
 
@@ -281,12 +367,12 @@ mod tests {
             true,
             true,
             &[
-                vec![26840],
-                vec![26839],
-                vec![26838],
-                vec![26837],
-                vec![26836],
-                vec![26835],
+                vec![26840, 26838],
+                vec![26839, 26837],
+                vec![26838, 26836],
+                vec![26837, 26835],
+                vec![26836, 26834],
+                vec![26835, 26833],
             ],
         );
 
@@ -296,12 +382,12 @@ mod tests {
             false,
             true,
             &[
-                vec![26842],
-                vec![101117],
-                vec![101152, 101153],
-                vec![101115],
-                vec![101114],
-                vec![101113],
+                vec![26842, 26840],
+                vec![101117, 101154, 101115, 101151],
+                vec![101152, 101153, 101150, 101116, 101114],
+                vec![101115, 101151, 101113, 101149],
+                vec![101114, 101150, 101112, 101148],
+                vec![101113, 101149, 101111, 101147],
             ],
         );
 
@@ -311,21 +397,22 @@ mod tests {
             true,
             true,
             &[
-                vec![26841],
-                vec![26840],
-                vec![26839],
-                vec![26838],
-                vec![26837],
-                vec![26836],
+                vec![26841, 26839],
+                vec![26840, 26838],
+                vec![26839, 26837],
+                vec![26838, 26836],
+                vec![26837, 26835],
+                vec![26836, 26834],
             ],
         );
 
-        // Conclusion: There are 2 setups that have a 2-advances window.
+        // Targets are ranked by hit probability, so windows of good advances come first
     }
 
     #[test]
     fn test_gen3_pokerus_generator_states_no_filter() {
         let opts = Pokerus3GeneratorOptions {
+            version_options: Pokerus3VersionOptions::Rs(RsTidSidOptions::DeadBattery),
             initial_advance_before_pickup: 10022,
             max_advances: 1,
             entered_hall_of_fame: true,
@@ -333,7 +420,7 @@ mod tests {
             has_empty_pokenews_slot: true,
             level_up: false,
             filter_pickup_items: None,
-            pickup_pokemon_count: 5,
+            pickup_levels: vec![5; 5],
             filter_gives_pokerus: None,
         };
 
@@ -344,27 +431,15 @@ mod tests {
             vec![
                 Pokerus3GeneratorResult {
                     advance_before_pickup: 10022,
-                    pickup_items: vec![
-                        PickUpItem::None,
-                        PickUpItem::None,
-                        PickUpItem::None,
-                        PickUpItem::None,
-                        PickUpItem::FullRestore
-                    ],
-                    advance_before_pokerus: 10109,
-                    gives_pokerus: false,
+                    pickup_items: vec![None, None, None, None, Some(Gen3PickupItem::FullRestore)],
+                    outcomes: rs_outcomes(10109, [false, false]),
+                    pokerus_chance: 0.0,
                 },
                 Pokerus3GeneratorResult {
                     advance_before_pickup: 10023,
-                    pickup_items: vec![
-                        PickUpItem::None,
-                        PickUpItem::None,
-                        PickUpItem::None,
-                        PickUpItem::FullRestore,
-                        PickUpItem::None
-                    ],
-                    advance_before_pokerus: 10110,
-                    gives_pokerus: false,
+                    pickup_items: vec![None, None, None, Some(Gen3PickupItem::FullRestore), None],
+                    outcomes: rs_outcomes(10110, [false, false]),
+                    pokerus_chance: 0.0,
                 }
             ]
         );
@@ -373,6 +448,7 @@ mod tests {
     #[test]
     fn test_gen3_pokerus_generator_states_with_filter() {
         let opts = Pokerus3GeneratorOptions {
+            version_options: Pokerus3VersionOptions::Rs(RsTidSidOptions::DeadBattery),
             initial_advance_before_pickup: 40000,
             max_advances: 10000,
             entered_hall_of_fame: true,
@@ -380,13 +456,13 @@ mod tests {
             has_empty_pokenews_slot: true,
             level_up: false,
             filter_pickup_items: Some(vec![
-                PickUpItem::None,
-                PickUpItem::None,
-                PickUpItem::UltraBall,
-                PickUpItem::FullRestore,
-                PickUpItem::None,
+                None,
+                None,
+                Some(Gen3PickupItem::UltraBall),
+                Some(Gen3PickupItem::FullRestore),
+                None,
             ]),
-            pickup_pokemon_count: 5,
+            pickup_levels: vec![5; 5],
             filter_gives_pokerus: None,
         };
 
@@ -397,14 +473,14 @@ mod tests {
             vec![Pokerus3GeneratorResult {
                 advance_before_pickup: 44108,
                 pickup_items: vec![
-                    PickUpItem::None,
-                    PickUpItem::None,
-                    PickUpItem::UltraBall,
-                    PickUpItem::FullRestore,
-                    PickUpItem::None
+                    None,
+                    None,
+                    Some(Gen3PickupItem::UltraBall),
+                    Some(Gen3PickupItem::FullRestore),
+                    None
                 ],
-                advance_before_pokerus: 44196,
-                gives_pokerus: false,
+                outcomes: rs_outcomes(44196, [false, false]),
+                pokerus_chance: 0.0,
             }]
         );
     }
@@ -412,14 +488,18 @@ mod tests {
     #[test]
     fn test_gen3_pokerus_generator_states_with_filter_2_pickup_pokemon() {
         let opts = Pokerus3GeneratorOptions {
+            version_options: Pokerus3VersionOptions::Rs(RsTidSidOptions::DeadBattery),
             initial_advance_before_pickup: 15000,
             max_advances: 30000,
             entered_hall_of_fame: false,
             can_have_new_mass_outbreak: false,
             has_empty_pokenews_slot: false,
             level_up: false,
-            filter_pickup_items: Some(vec![PickUpItem::UltraBall, PickUpItem::FullRestore]),
-            pickup_pokemon_count: 2,
+            filter_pickup_items: Some(vec![
+                Some(Gen3PickupItem::UltraBall),
+                Some(Gen3PickupItem::FullRestore),
+            ]),
+            pickup_levels: vec![5; 2],
             filter_gives_pokerus: None,
         };
 
@@ -430,15 +510,21 @@ mod tests {
             vec![
                 Pokerus3GeneratorResult {
                     advance_before_pickup: 15713,
-                    pickup_items: vec![PickUpItem::UltraBall, PickUpItem::FullRestore],
-                    advance_before_pokerus: 15796,
-                    gives_pokerus: false
+                    pickup_items: vec![
+                        Some(Gen3PickupItem::UltraBall),
+                        Some(Gen3PickupItem::FullRestore)
+                    ],
+                    outcomes: rs_outcomes(15796, [false, false]),
+                    pokerus_chance: 0.0,
                 },
                 Pokerus3GeneratorResult {
                     advance_before_pickup: 44110,
-                    pickup_items: vec![PickUpItem::UltraBall, PickUpItem::FullRestore],
-                    advance_before_pokerus: 44193,
-                    gives_pokerus: false
+                    pickup_items: vec![
+                        Some(Gen3PickupItem::UltraBall),
+                        Some(Gen3PickupItem::FullRestore)
+                    ],
+                    outcomes: rs_outcomes(44193, [false, false]),
+                    pokerus_chance: 0.0,
                 }
             ]
         );
@@ -447,6 +533,7 @@ mod tests {
     #[test]
     fn test_gen3_pokerus_generator_states_gives_pokerus_not_hof() {
         let opts = Pokerus3GeneratorOptions {
+            version_options: Pokerus3VersionOptions::Rs(RsTidSidOptions::DeadBattery),
             initial_advance_before_pickup: 0,
             max_advances: 30000,
             entered_hall_of_fame: false,
@@ -454,7 +541,7 @@ mod tests {
             has_empty_pokenews_slot: true,
             level_up: false,
             filter_pickup_items: None,
-            pickup_pokemon_count: 5,
+            pickup_levels: vec![5; 5],
             filter_gives_pokerus: Some(true),
         };
 
@@ -464,24 +551,132 @@ mod tests {
             results,
             vec![Pokerus3GeneratorResult {
                 advance_before_pickup: 26838,
-                pickup_items: vec![
-                    PickUpItem::None,
-                    PickUpItem::None,
-                    PickUpItem::None,
-                    PickUpItem::RareCandy,
-                    PickUpItem::None,
-                ],
-                advance_before_pokerus: 26923,
-                gives_pokerus: true,
+                pickup_items: vec![None, None, None, Some(Gen3PickupItem::RareCandy), None,],
+                outcomes: rs_outcomes(26923, [true, false]),
+                pokerus_chance: 0.8,
             }]
         );
     }
 
+    fn version_options(version_options: Pokerus3VersionOptions) -> Pokerus3GeneratorOptions {
+        Pokerus3GeneratorOptions {
+            version_options,
+            initial_advance_before_pickup: 1000,
+            max_advances: 500,
+            entered_hall_of_fame: true,
+            can_have_new_mass_outbreak: true,
+            has_empty_pokenews_slot: true,
+            level_up: false,
+            filter_pickup_items: None,
+            pickup_levels: vec![5; 2],
+            filter_gives_pokerus: None,
+        }
+    }
+
+    #[test]
+    fn test_gen3_pokerus_generator_states_emerald_pickup() {
+        let opts = Pokerus3GeneratorOptions {
+            pickup_levels: vec![35, 100],
+            ..version_options(Pokerus3VersionOptions::Emerald)
+        };
+        let emerald = gen3_pokerus_generator_states(&opts);
+        let pickups = gen3_pickup_states(&Gen3PickupOptions {
+            game: Gen3Game::Emerald,
+            seed: 0,
+            initial_advances: 1000,
+            max_advances: 500,
+            levels: vec![35, 100],
+            wanted_items: vec![],
+        });
+
+        assert_eq!(emerald.len(), pickups.len());
+        emerald.iter().zip(pickups).for_each(|(result, pickup)| {
+            assert_eq!(result.advance_before_pickup, pickup.advance);
+            assert_eq!(result.pickup_items, pickup.items);
+        });
+    }
+
+    #[test]
+    fn test_gen3_pokerus_generator_states_emerald_post_battle() {
+        let opts = version_options(Pokerus3VersionOptions::Emerald);
+        let hall_of_fame = gen3_pokerus_generator_states(&opts);
+        let no_hall_of_fame = gen3_pokerus_generator_states(&Pokerus3GeneratorOptions {
+            entered_hall_of_fame: false,
+            ..opts
+        });
+
+        hall_of_fame
+            .iter()
+            .zip(no_hall_of_fame)
+            .for_each(|(hall_of_fame, no_hall_of_fame)| {
+                // The PokéNews roll comes right after the vblanks, before the TV rand
+                let mut rng = Pokerng::new(0);
+                rng.advance(
+                    no_hall_of_fame.outcomes[0].advance_before_pokerus
+                        - TV_VBLANKS_BEFORE_POKERUS
+                        - 1,
+                );
+                let mut extra_rands = 2;
+                if rng.rand::<u16>() % 100 == 0 {
+                    rng.rand::<u16>();
+                    extra_rands += 1;
+                }
+                if rng.rand::<u16>() % 200 == 0 {
+                    extra_rands += 1;
+                }
+
+                assert_eq!(
+                    hall_of_fame.outcomes[0].advance_before_pokerus,
+                    no_hall_of_fame.outcomes[0].advance_before_pokerus + extra_rands
+                );
+            });
+    }
+
+    #[test]
+    fn test_gen3_pokerus_generator_states_frlg_has_no_tv_shows() {
+        let frlg = Pokerus3VersionOptions::Frlg(0x1234);
+        let hall_of_fame = gen3_pokerus_generator_states(&version_options(frlg.clone()));
+        let no_hall_of_fame = gen3_pokerus_generator_states(&Pokerus3GeneratorOptions {
+            entered_hall_of_fame: false,
+            ..version_options(frlg)
+        });
+
+        assert_eq!(hall_of_fame, no_hall_of_fame);
+        hall_of_fame.iter().for_each(|result| {
+            let pickup_rands = result.outcomes[0].advance_before_pokerus
+                - result.advance_before_pickup
+                - 4
+                - TV_VBLANKS_BEFORE_POKERUS;
+            assert!((2..=4).contains(&pickup_rands));
+        });
+    }
+
+    #[test]
+    fn test_gen3_pokerus_generator_states_weighs_vblank_outcomes() {
+        let results = gen3_pokerus_generator_states(&Pokerus3GeneratorOptions {
+            initial_advance_before_pickup: 0,
+            max_advances: 30000,
+            entered_hall_of_fame: false,
+            filter_gives_pokerus: Some(true),
+            pickup_levels: vec![5; 5],
+            ..version_options(Pokerus3VersionOptions::Rs(RsTidSidOptions::DeadBattery))
+        });
+
+        // Either vblank outcome may be the one that gives Pokérus
+        assert!(results.iter().any(|result| result.pokerus_chance == 0.8));
+        results.iter().for_each(|result| {
+            let [four, six] = [&result.outcomes[0], &result.outcomes[1]];
+            assert_eq!(four.advance_before_pokerus + 2, six.advance_before_pokerus);
+            assert!(four.gives_pokerus || six.gives_pokerus);
+        });
+    }
+
     /*
     // Kept to help future debugging
     #[test]
     fn test_debug() {
         println!("{:?}", gen3_pokerus_generator_states(&Pokerus3GeneratorOptions {
+            version_options: Pokerus3VersionOptions::Rs(RsTidSidOptions::DeadBattery),
             initial_advance_before_pickup: 26000,
             max_advances: 1000,
             entered_hall_of_fame: false,
@@ -489,7 +684,7 @@ mod tests {
             level_up: false,
             has_empty_pokenews_slot: true,
             filter_pickup_items: None,
-            pickup_pokemon_count: 2,
+            pickup_levels: vec![5; 2],
             filter_gives_pokerus: Some(true),
         }));
         assert!(false);
@@ -0,0 +1,367 @@
+use super::{
+    Gen3Game, Static3GeneratorOptions, Static3GeneratorResult, gen3_static_generator_states,
+};
+use crate::rng::Rng;
+use crate::rng::lcrng::Pokerng;
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Gen3Route {
+    // Hoenn
+    Route110,
+    Route111,
+    Route117,
+    Route118,
+    Route119,
+    Route120,
+    Route121,
+    Route122,
+    Route123,
+    Route124,
+    Route125,
+    Route126,
+    Route127,
+    Route128,
+    Route129,
+    Route130,
+    Route131,
+    Route132,
+    Route133,
+    Route134,
+    // Kanto
+    Route1,
+    Route2,
+    Route3,
+    Route4,
+    Route5,
+    Route6,
+    Route7,
+    Route8,
+    Route9,
+    Route10,
+    Route11,
+    Route12,
+    Route13,
+    Route14,
+    Route15,
+    Route16,
+    Route17,
+    Route18,
+    Route21North,
+    Route21South,
+    Route22,
+    Route24,
+}
+
+use Gen3Route::*;
+
+// Each set starts with the roamer's route, followed by the routes it can walk to
+const HOENN_LOCATION_SETS: [&[Gen3Route]; 20] = [
+    &[Route110, Route111, Route117, Route118, Route134],
+    &[Route111, Route110, Route117, Route118],
+    &[Route117, Route111, Route110, Route118],
+    &[Route118, Route117, Route110, Route111, Route119, Route123],
+    &[Route119, Route118, Route120],
+    &[Route120, Route119, Route121],
+    &[Route121, Route120, Route122, Route123],
+    &[Route122, Route121, Route123],
+    &[Route123, Route122, Route118],
+    &[Route124, Route121, Route125, Route126],
+    &[Route125, Route124, Route127],
+    &[Route126, Route124, Route127],
+    &[Route127, Route125, Route126, Route128],
+    &[Route128, Route127, Route129],
+    &[Route129, Route128, Route130],
+    &[Route130, Route129, Route131],
+    &[Route131, Route130, Route132],
+    &[Route132, Route131, Route133],
+    &[Route133, Route131, Route132, Route134],
+    &[Route134, Route132, Route133, Route110],
+];
+const HOENN_LOCATIONS_PER_SET: u16 = 6;
+
+const KANTO_LOCATION_SETS: [&[Gen3Route]; 22] = [
+    &[Route1, Route2, Route21North, Route22],
+    &[Route2, Route1, Route3, Route22],
+    &[Route3, Route2, Route4, Route22],
+    &[Route4, Route3, Route5, Route9, Route24],
+    &[Route5, Route4, Route6, Route7, Route8, Route9, Route24],
+    &[Route6, Route5, Route7, Route8, Route11],
+    &[Route7, Route5, Route6, Route8, Route16],
+    &[Route8, Route5, Route6, Route7, Route10, Route12],
+    &[Route9, Route4, Route5, Route10, Route24],
+    &[Route10, Route8, Route9, Route12],
+    &[Route11, Route6, Route12],
+    &[Route12, Route10, Route11, Route13],
+    &[Route13, Route12, Route14],
+    &[Route14, Route13, Route15],
+    &[Route15, Route14, Route18],
+    &[Route16, Route7, Route17],
+    &[Route17, Route16, Route18],
+    &[Route18, Route15, Route17],
+    &[Route21North, Route1, Route21South],
+    &[Route21South, Route21North],
+    &[Route22, Route1, Route2, Route3],
+    &[Route24, Route4, Route5, Route9],
+];
+const KANTO_LOCATIONS_PER_SET: u16 = 7;
+
+fn location_sets(game: Gen3Game) -> (&'static [&'static [Gen3Route]], u16) {
+    if game.is_frlg() {
+        (&KANTO_LOCATION_SETS, KANTO_LOCATIONS_PER_SET)
+    } else {
+        (&HOENN_LOCATION_SETS, HOENN_LOCATIONS_PER_SET)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Gen3RoamerMoveKind {
+    // The roamer is placed at the start of a random location set
+    Release,
+    // Walking across a map connection
+    Connection,
+    // Any warp, such as a door or Fly, jumps the roamer to another location set
+    Warp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen3RoamerMove {
+    pub advance: usize,
+    pub kind: Gen3RoamerMoveKind,
+    // None when the map isn't one the roamer can be on, such as a town
+    pub player_route: Option<Gen3Route>,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen3RoamerRouteOptions {
+    pub game: Gen3Game,
+    pub seed: u32,
+    // None when the roamer hasn't been released yet
+    pub roamer_route: Option<Gen3Route>,
+    pub player_route: Option<Gen3Route>,
+    pub player_last_route: Option<Gen3Route>,
+    pub moves: Vec<Gen3RoamerMove>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen3RoamerStep {
+    pub advance: usize,
+    pub roamer_route: Option<Gen3Route>,
+}
+
+fn release_roamer(rng: &mut Pokerng, game: Gen3Game) -> Gen3Route {
+    let (sets, _) = location_sets(game);
+    sets[rng.rand_max::<u16>(sets.len() as u16) as usize][0]
+}
+
+fn jump_roamer(rng: &mut Pokerng, game: Gen3Game, route: Option<Gen3Route>) -> Option<Gen3Route> {
+    route?;
+    let (sets, _) = location_sets(game);
+    loop {
+        let next_route = sets[rng.rand_max::<u16>(sets.len() as u16) as usize][0];
+        if Some(next_route) != route {
+            return Some(next_route);
+        }
+    }
+}
+
+// The roamer won't walk onto the route the player was on two map changes ago
+fn move_roamer(
+    rng: &mut Pokerng,
+    game: Gen3Game,
+    route: Option<Gen3Route>,
+    avoided_route: Option<Gen3Route>,
+) -> Option<Gen3Route> {
+    if rng.rand_max::<u16>(16) == 0 {
+        return jump_roamer(rng, game, route);
+    }
+
+    let (sets, locations_per_set) = location_sets(game);
+    let set = sets.iter().find(|set| Some(set[0]) == route)?;
+    loop {
+        let index = rng.rand_max::<u16>(locations_per_set - 1) as usize + 1;
+        match set.get(index) {
+            Some(&next_route) if Some(next_route) != avoided_route => return Some(next_route),
+            _ => continue,
+        }
+    }
+}
+
+#[wasm_bindgen]
+pub fn gen3_predict_roamer_routes(opts: &Gen3RoamerRouteOptions) -> Vec<Gen3RoamerStep> {
+    let mut roamer_route = opts.roamer_route;
+    let mut history = [opts.player_route, opts.player_last_route];
+
+    opts.moves
+        .iter()
+        .map(|player_move| {
+            let mut rng = Pokerng::new(opts.seed);
+            rng.advance(player_move.advance);
+
+            let avoided_route = history[1];
+            history = [player_move.player_route, history[0]];
+
+            roamer_route = match player_move.kind {
+                Gen3RoamerMoveKind::Release => Some(release_roamer(&mut rng, opts.game)),
+                Gen3RoamerMoveKind::Connection => {
+                    move_roamer(&mut rng, opts.game, roamer_route, avoided_route)
+                }
+                Gen3RoamerMoveKind::Warp => jump_roamer(&mut rng, opts.game, roamer_route),
+            };
+
+            Gen3RoamerStep {
+                advance: player_move.advance,
+                roamer_route,
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen3RoamerPlanResult {
+    pub state: Static3GeneratorResult,
+    pub release_route: Gen3Route,
+}
+
+// The roamer's starting route is rolled right after its PID and IVs
+#[wasm_bindgen]
+pub fn gen3_roamer_plan(
+    game: Gen3Game,
+    opts: &Static3GeneratorOptions,
+) -> Vec<Gen3RoamerPlanResult> {
+    let opts = Static3GeneratorOptions {
+        bugged_roamer: true,
+        method4: false,
+        ..opts.clone()
+    };
+
+    gen3_static_generator_states(&opts)
+        .into_iter()
+        .map(|state| {
+            let mut rng = Pokerng::new(opts.seed);
+            rng.advance(opts.offset + state.advance + 4);
+            Gen3RoamerPlanResult {
+                state,
+                release_route: release_roamer(&mut rng, game),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{PkmFilter, Species};
+
+    fn route_options(game: Gen3Game, moves: Vec<Gen3RoamerMove>) -> Gen3RoamerRouteOptions {
+        Gen3RoamerRouteOptions {
+            game,
+            seed: 0x1234,
+            roamer_route: None,
+            player_route: None,
+            player_last_route: None,
+            moves,
+        }
+    }
+
+    #[test]
+    fn moves_roamer_between_connected_routes() {
+        let mut moves = vec![Gen3RoamerMove {
+            advance: 10,
+            kind: Gen3RoamerMoveKind::Release,
+            player_route: None,
+        }];
+        moves.extend((1..100).map(|step| Gen3RoamerMove {
+            advance: 10 + step * 7,
+            kind: Gen3RoamerMoveKind::Connection,
+            player_route: Some(Route1),
+        }));
+
+        for game in [Gen3Game::Emerald, Gen3Game::FireRed] {
+            let (sets, _) = location_sets(game);
+            let steps = gen3_predict_roamer_routes(&route_options(game, moves.clone()));
+
+            assert_eq!(steps.len(), 100);
+            steps.windows(2).for_each(|steps| {
+                let last = steps[0].roamer_route.unwrap();
+                let route = steps[1].roamer_route.unwrap();
+                let set = sets.iter().find(|set| set[0] == last).unwrap();
+                // Roamers jump to the start of any other set one in sixteen moves
+                assert!(set[1..].contains(&route) || sets.iter().any(|set| set[0] == route));
+            });
+        }
+    }
+
+    #[test]
+    fn warps_roamer_to_other_set() {
+        let moves = (0..50)
+            .map(|step| Gen3RoamerMove {
+                advance: step * 3,
+                kind: Gen3RoamerMoveKind::Warp,
+                player_route: None,
+            })
+            .collect();
+        let opts = Gen3RoamerRouteOptions {
+            roamer_route: Some(Route110),
+            ..route_options(Gen3Game::Ruby, moves)
+        };
+        let steps = gen3_predict_roamer_routes(&opts);
+
+        let mut last = Route110;
+        steps.iter().for_each(|step| {
+            let route = step.roamer_route.unwrap();
+            assert_ne!(route, last);
+            assert!(HOENN_LOCATION_SETS.iter().any(|set| set[0] == route));
+            last = route;
+        });
+    }
+
+    #[test]
+    fn unreleased_roamer_has_no_route() {
+        let moves = vec![Gen3RoamerMove {
+            advance: 0,
+            kind: Gen3RoamerMoveKind::Connection,
+            player_route: None,
+        }];
+        let steps = gen3_predict_roamer_routes(&route_options(Gen3Game::LeafGreen, moves));
+
+        assert_eq!(steps[0].roamer_route, None);
+    }
+
+    #[test]
+    fn plans_bugged_roamer() {
+        let opts = Static3GeneratorOptions {
+            offset: 0,
+            initial_advances: 0,
+            max_advances: 20,
+            seed: 0,
+            species: Species::Latias,
+            bugged_roamer: false,
+            method4: false,
+            tid: 0,
+            sid: 0,
+            filter: PkmFilter::new_allow_all(),
+        };
+        let results = gen3_roamer_plan(Gen3Game::Emerald, &opts);
+
+        assert_eq!(results.len(), 21);
+        results.iter().for_each(|result| {
+            assert_eq!(result.state.ivs.def, 0);
+            assert!(result.state.ivs.atk < 8);
+            let mut rng = Pokerng::new(0);
+            rng.advance(result.state.advance + 4);
+            assert_eq!(
+                result.release_route,
+                release_roamer(&mut rng, Gen3Game::Emerald)
+            );
+        });
+    }
+}
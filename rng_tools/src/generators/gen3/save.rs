@@ -0,0 +1,597 @@
+use super::jirachi::gba_save::{
+    BOX_MON_LEN, BOX_SLOTS, Gen3SaveVersion, PARTY_MON_LEN, Sav3, Sav3ReadError,
+};
+use crate::{AbilityType, Ivs, Nature, Species, gen3_shiny};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+// Gen 3 skips 252 to 276, then stores Hoenn Pokémon in this order
+const HOENN_INTERNAL_SPECIES: [Species; 135] = [
+    Species::Treecko,
+    Species::Grovyle,
+    Species::Sceptile,
+    Species::Torchic,
+    Species::Combusken,
+    Species::Blaziken,
+    Species::Mudkip,
+    Species::Marshtomp,
+    Species::Swampert,
+    Species::Poochyena,
+    Species::Mightyena,
+    Species::Zigzagoon,
+    Species::Linoone,
+    Species::Wurmple,
+    Species::Silcoon,
+    Species::Beautifly,
+    Species::Cascoon,
+    Species::Dustox,
+    Species::Lotad,
+    Species::Lombre,
+    Species::Ludicolo,
+    Species::Seedot,
+    Species::Nuzleaf,
+    Species::Shiftry,
+    Species::Nincada,
+    Species::Ninjask,
+    Species::Shedinja,
+    Species::Taillow,
+    Species::Swellow,
+    Species::Shroomish,
+    Species::Breloom,
+    Species::Spinda,
+    Species::Wingull,
+    Species::Pelipper,
+    Species::Surskit,
+    Species::Masquerain,
+    Species::Wailmer,
+    Species::Wailord,
+    Species::Skitty,
+    Species::Delcatty,
+    Species::Kecleon,
+    Species::Baltoy,
+    Species::Claydol,
+    Species::Nosepass,
+    Species::Torkoal,
+    Species::Sableye,
+    Species::Barboach,
+    Species::Whiscash,
+    Species::Luvdisc,
+    Species::Corphish,
+    Species::Crawdaunt,
+    Species::Feebas,
+    Species::Milotic,
+    Species::Carvanha,
+    Species::Sharpedo,
+    Species::Trapinch,
+    Species::Vibrava,
+    Species::Flygon,
+    Species::Makuhita,
+    Species::Hariyama,
+    Species::Electrike,
+    Species::Manectric,
+    Species::Numel,
+    Species::Camerupt,
+    Species::Spheal,
+    Species::Sealeo,
+    Species::Walrein,
+    Species::Cacnea,
+    Species::Cacturne,
+    Species::Snorunt,
+    Species::Glalie,
+    Species::Lunatone,
+    Species::Solrock,
+    Species::Azurill,
+    Species::Spoink,
+    Species::Grumpig,
+    Species::Plusle,
+    Species::Minun,
+    Species::Mawile,
+    Species::Meditite,
+    Species::Medicham,
+    Species::Swablu,
+    Species::Altaria,
+    Species::Wynaut,
+    Species::Duskull,
+    Species::Dusclops,
+    Species::Roselia,
+    Species::Slakoth,
+    Species::Vigoroth,
+    Species::Slaking,
+    Species::Gulpin,
+    Species::Swalot,
+    Species::Tropius,
+    Species::Whismur,
+    Species::Loudred,
+    Species::Exploud,
+    Species::Clamperl,
+    Species::Huntail,
+    Species::Gorebyss,
+    Species::Absol,
+    Species::Shuppet,
+    Species::Banette,
+    Species::Seviper,
+    Species::Zangoose,
+    Species::Relicanth,
+    Species::Aron,
+    Species::Lairon,
+    Species::Aggron,
+    Species::Castform,
+    Species::Volbeat,
+    Species::Illumise,
+    Species::Lileep,
+    Species::Cradily,
+    Species::Anorith,
+    Species::Armaldo,
+    Species::Ralts,
+    Species::Kirlia,
+    Species::Gardevoir,
+    Species::Bagon,
+    Species::Shelgon,
+    Species::Salamence,
+    Species::Beldum,
+    Species::Metang,
+    Species::Metagross,
+    Species::Regirock,
+    Species::Regice,
+    Species::Registeel,
+    Species::Kyogre,
+    Species::Groudon,
+    Species::Rayquaza,
+    Species::Latias,
+    Species::Latios,
+    Species::Jirachi,
+    Species::Deoxys,
+    Species::Chimecho,
+];
+const FIRST_HOENN_INTERNAL_SPECIES: u16 = 277;
+
+// The substructure at each position for each PID % 24, as growth (0), attacks (1), EVs (2) and misc (3)
+const SUBSTRUCTURE_ORDERS: [[u8; 4]; 24] = [
+    [0, 1, 2, 3],
+    [0, 1, 3, 2],
+    [0, 2, 1, 3],
+    [0, 2, 3, 1],
+    [0, 3, 1, 2],
+    [0, 3, 2, 1],
+    [1, 0, 2, 3],
+    [1, 0, 3, 2],
+    [1, 2, 0, 3],
+    [1, 2, 3, 0],
+    [1, 3, 0, 2],
+    [1, 3, 2, 0],
+    [2, 0, 1, 3],
+    [2, 0, 3, 1],
+    [2, 1, 0, 3],
+    [2, 1, 3, 0],
+    [2, 3, 0, 1],
+    [2, 3, 1, 0],
+    [3, 0, 1, 2],
+    [3, 0, 2, 1],
+    [3, 1, 0, 2],
+    [3, 1, 2, 0],
+    [3, 2, 0, 1],
+    [3, 2, 1, 0],
+];
+
+const GROWTH: usize = 0;
+const MISC: usize = 3;
+const SUBSTRUCTURE_LEN: usize = 12;
+
+fn le_u16(bytes: &[u8]) -> u16 {
+    u16::from_le_bytes([bytes[0], bytes[1]])
+}
+
+fn le_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+pub fn species_from_gen3_index(index: u16) -> Option<Species> {
+    match index {
+        1..=251 => Some(Species::from(index)),
+        _ => HOENN_INTERNAL_SPECIES
+            .get(index.checked_sub(FIRST_HOENN_INTERNAL_SPECIES)? as usize)
+            .copied(),
+    }
+}
+
+// Only the English character set is decoded
+fn decode_gen3_char(byte: u8) -> Option<char> {
+    match byte {
+        0x00 => Some(' '),
+        0xa1..=0xaa => Some((byte - 0xa1 + b'0') as char),
+        0xab => Some('!'),
+        0xac => Some('?'),
+        0xad => Some('.'),
+        0xae => Some('-'),
+        0xb0 => Some('…'),
+        0xb1 => Some('“'),
+        0xb2 => Some('”'),
+        0xb3 => Some('‘'),
+        0xb4 => Some('’'),
+        0xb5 => Some('♂'),
+        0xb6 => Some('♀'),
+        0xb8 => Some(','),
+        0xba => Some('/'),
+        0xbb..=0xd4 => Some((byte - 0xbb + b'A') as char),
+        0xd5..=0xee => Some((byte - 0xd5 + b'a') as char),
+        _ => None,
+    }
+}
+
+fn decode_gen3_string(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .take_while(|&&byte| byte != 0xff)
+        .map(|&byte| decode_gen3_char(byte).unwrap_or('?'))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen3SaveTime {
+    pub days: i16,
+    pub hours: i8,
+    pub minutes: i8,
+    pub seconds: i8,
+}
+
+impl From<(i16, i8, i8, i8)> for Gen3SaveTime {
+    fn from((days, hours, minutes, seconds): (i16, i8, i8, i8)) -> Self {
+        Self {
+            days,
+            hours,
+            minutes,
+            seconds,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Gen3SaveLocation {
+    Party { slot: u8 },
+    Box { box_index: u8, slot: u8 },
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen3SavePokemon {
+    pub location: Gen3SaveLocation,
+    pub pid: u32,
+    pub tid: u16,
+    pub sid: u16,
+    pub species: Species,
+    pub nature: Nature,
+    pub ability: AbilityType,
+    pub ivs: Ivs,
+    pub shiny: bool,
+    pub is_egg: bool,
+}
+
+impl Gen3SavePokemon {
+    // Returns None for empty slots and Pokémon that fail their checksum
+    pub fn from_bytes(bytes: &[u8], location: Gen3SaveLocation) -> Option<Self> {
+        let pid = le_u32(&bytes[0x0..]);
+        let otid = le_u32(&bytes[0x4..]);
+        let checksum = le_u16(&bytes[0x1c..]);
+        let key = pid ^ otid;
+
+        let data = bytes[0x20..][..SUBSTRUCTURE_LEN * 4]
+            .chunks_exact(4)
+            .flat_map(|word| (le_u32(word) ^ key).to_le_bytes())
+            .collect::<Vec<u8>>();
+
+        let data_checksum = data
+            .chunks_exact(2)
+            .map(le_u16)
+            .fold(0u16, u16::wrapping_add);
+        if data_checksum != checksum {
+            return None;
+        }
+
+        let order = SUBSTRUCTURE_ORDERS[(pid % 24) as usize];
+        let substructure = |kind: usize| {
+            let position = order
+                .iter()
+                .position(|&sub| sub as usize == kind)
+                .unwrap_or_default();
+            &data[position * SUBSTRUCTURE_LEN..][..SUBSTRUCTURE_LEN]
+        };
+
+        let species = species_from_gen3_index(le_u16(substructure(GROWTH)))?;
+        if species == Species::None {
+            return None;
+        }
+
+        let iv_word = le_u32(&substructure(MISC)[4..]);
+        let ivs = Ivs {
+            hp: (iv_word & 0x1f) as u8,
+            atk: ((iv_word >> 5) & 0x1f) as u8,
+            def: ((iv_word >> 10) & 0x1f) as u8,
+            spe: ((iv_word >> 15) & 0x1f) as u8,
+            spa: ((iv_word >> 20) & 0x1f) as u8,
+            spd: ((iv_word >> 25) & 0x1f) as u8,
+        };
+        let tid = otid as u16;
+        let sid = (otid >> 16) as u16;
+
+        Some(Self {
+            location,
+            pid,
+            tid,
+            sid,
+            species,
+            nature: Nature::from_pid(pid),
+            ability: AbilityType::from((iv_word >> 31) as u8),
+            ivs,
+            shiny: gen3_shiny(pid, tid, sid),
+            is_egg: (iv_word >> 30) & 1 == 1,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen3SaveInfo {
+    pub version: Gen3SaveVersion,
+    pub trainer_name: String,
+    pub tid: u16,
+    pub sid: u16,
+    // The in-game clock is the cartridge clock plus this offset.
+    // FireRed and LeafGreen have no clock.
+    pub rtc_offset: Option<Gen3SaveTime>,
+    pub last_berry_update: Option<Gen3SaveTime>,
+    pub party: Vec<Gen3SavePokemon>,
+    pub boxes: Vec<Gen3SavePokemon>,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Gen3SaveOptions {
+    pub save: Vec<u8>,
+}
+
+#[wasm_bindgen]
+pub fn gen3_read_save(opts: Gen3SaveOptions) -> Result<Gen3SaveInfo, Sav3ReadError> {
+    let save = Sav3::new(&opts.save)?;
+    let version = save.version();
+    let trainer_info = save.trainer_info();
+    let (tid, sid) = trainer_info.trainer_id();
+    let has_rtc = version != Gen3SaveVersion::FireRedLeafGreen;
+
+    let party = save
+        .party()
+        .into_iter()
+        .enumerate()
+        .filter_map(|(slot, mon)| {
+            Gen3SavePokemon::from_bytes(
+                &mon[..PARTY_MON_LEN],
+                Gen3SaveLocation::Party { slot: slot as u8 },
+            )
+        })
+        .collect();
+
+    let boxes = save
+        .box_mons()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, mon)| {
+            Gen3SavePokemon::from_bytes(
+                &mon[..BOX_MON_LEN],
+                Gen3SaveLocation::Box {
+                    box_index: (index / BOX_SLOTS) as u8,
+                    slot: (index % BOX_SLOTS) as u8,
+                },
+            )
+        })
+        .collect();
+
+    Ok(Gen3SaveInfo {
+        version,
+        trainer_name: decode_gen3_string(trainer_info.trainer_name()),
+        tid,
+        sid,
+        rtc_offset: has_rtc.then(|| trainer_info.rtc_offset().into()),
+        last_berry_update: has_rtc.then(|| trainer_info.last_berry_update().into()),
+        party,
+        boxes,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gen3::jirachi::gba_save::test_saves::{write_checksums, write_slot_footers};
+
+    const SECTOR_LEN: usize = 0x1000;
+
+    fn encode_mon(pid: u32, otid: u32, species: u16, iv_word: u32) -> Vec<u8> {
+        let mut substructures = [[0u8; SUBSTRUCTURE_LEN]; 4];
+        substructures[GROWTH][..2].copy_from_slice(&species.to_le_bytes());
+        substructures[MISC][4..8].copy_from_slice(&iv_word.to_le_bytes());
+
+        let order = SUBSTRUCTURE_ORDERS[(pid % 24) as usize];
+        let data = order
+            .iter()
+            .flat_map(|&sub| substructures[sub as usize])
+            .collect::<Vec<u8>>();
+        let checksum = data
+            .chunks_exact(2)
+            .map(le_u16)
+            .fold(0u16, u16::wrapping_add);
+
+        let mut mon = vec![0u8; BOX_MON_LEN];
+        mon[..4].copy_from_slice(&pid.to_le_bytes());
+        mon[4..8].copy_from_slice(&otid.to_le_bytes());
+        mon[0x1c..0x1e].copy_from_slice(&checksum.to_le_bytes());
+        data.chunks_exact(4).enumerate().for_each(|(index, word)| {
+            let encrypted = le_u32(word) ^ pid ^ otid;
+            mon[0x20 + index * 4..][..4].copy_from_slice(&encrypted.to_le_bytes());
+        });
+        mon
+    }
+
+    fn frlg_save(party_mon: &[u8], box_mon: &[u8]) -> Vec<u8> {
+        let mut save = vec![0u8; 131_072];
+        write_slot_footers(&mut save, 0, 1);
+
+        // "Red", TID 12345, SID 54321 and the FRLG game code
+        save[..4].copy_from_slice(&[0xcc, 0xd9, 0xd8, 0xff]);
+        save[0xa..0xc].copy_from_slice(&12345u16.to_le_bytes());
+        save[0xc..0xe].copy_from_slice(&54321u16.to_le_bytes());
+        save[0xac..0xb0].copy_from_slice(&1u32.to_le_bytes());
+
+        let team = &mut save[SECTOR_LEN..];
+        team[0x34..0x38].copy_from_slice(&1u32.to_le_bytes());
+        team[0x38..][..BOX_MON_LEN].copy_from_slice(party_mon);
+
+        // Box 2, slot 2
+        save[5 * SECTOR_LEN + 4 + 31 * BOX_MON_LEN..][..BOX_MON_LEN].copy_from_slice(box_mon);
+
+        write_checksums(&mut save);
+        save
+    }
+
+    #[test]
+    fn converts_internal_species() {
+        assert_eq!(species_from_gen3_index(25), Some(Species::Pikachu));
+        assert_eq!(species_from_gen3_index(260), None);
+        assert_eq!(species_from_gen3_index(277), Some(Species::Treecko));
+        assert_eq!(species_from_gen3_index(409), Some(Species::Jirachi));
+        assert_eq!(species_from_gen3_index(411), Some(Species::Chimecho));
+        assert_eq!(species_from_gen3_index(412), None);
+    }
+
+    #[test]
+    fn decrypts_pokemon() {
+        // HP 31, Atk 30, Def 29, Spe 28, SpA 27, SpD 26, second ability
+        let iv_word =
+            31 | (30 << 5) | (29 << 10) | (28 << 15) | (27 << 20) | (26 << 25) | (1 << 31);
+        for pid in 0..24 {
+            let mon = encode_mon(pid, 0x1234_5678, 409, iv_word);
+            let result =
+                Gen3SavePokemon::from_bytes(&mon, Gen3SaveLocation::Party { slot: 0 }).unwrap();
+
+            assert_eq!(result.species, Species::Jirachi);
+            assert_eq!(result.tid, 0x5678);
+            assert_eq!(result.sid, 0x1234);
+            assert_eq!(result.ability, AbilityType::Second);
+            assert!(!result.is_egg);
+            assert_eq!(
+                result.ivs,
+                Ivs {
+                    hp: 31,
+                    atk: 30,
+                    def: 29,
+                    spa: 27,
+                    spd: 26,
+                    spe: 28,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn decrypts_fixed_pokemon() {
+        // A Lonely Mudkip nicknamed "Mudkip", from OT "Red" with TID 24601 and SID 1234
+        const MUDKIP: [u8; BOX_MON_LEN] = [
+            0xa7, 0xe5, 0x19, 0x3c, 0x19, 0x60, 0xd2, 0x04, 0xc7, 0xe9, 0xd8, 0xdf, 0xdd, 0xe4,
+            0xff, 0x00, 0x00, 0x00, 0x02, 0x00, 0xcc, 0xd9, 0xd8, 0xff, 0x00, 0x00, 0x00, 0x00,
+            0x30, 0x88, 0x00, 0x00, 0x9f, 0x85, 0xe6, 0x38, 0xbe, 0x85, 0xcb, 0x38, 0x9d, 0xad,
+            0xcb, 0x38, 0xa5, 0x84, 0xcb, 0x38, 0x39, 0x85, 0xcb, 0x38, 0xbe, 0xc3, 0xcb, 0x38,
+            0xbe, 0x85, 0xcb, 0x38, 0x87, 0xfb, 0x2f, 0xa1, 0xbe, 0x85, 0xcb, 0x38, 0xbe, 0x85,
+            0xcb, 0x38, 0xbe, 0x85, 0xcb, 0x38, 0xbe, 0x85, 0xcb, 0x38,
+        ];
+        let result =
+            Gen3SavePokemon::from_bytes(&MUDKIP, Gen3SaveLocation::Party { slot: 0 }).unwrap();
+
+        assert_eq!(
+            result,
+            Gen3SavePokemon {
+                location: Gen3SaveLocation::Party { slot: 0 },
+                pid: 0x3c19e5a7,
+                tid: 24601,
+                sid: 1234,
+                species: Species::Mudkip,
+                nature: Nature::Lonely,
+                ability: AbilityType::Second,
+                ivs: Ivs {
+                    hp: 25,
+                    atk: 17,
+                    def: 31,
+                    spa: 30,
+                    spd: 12,
+                    spe: 8,
+                },
+                shiny: false,
+                is_egg: false,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_bad_checksum() {
+        let mut mon = encode_mon(0xdeadbeef, 0, 1, 0);
+        mon[0x1c] ^= 1;
+
+        assert_eq!(
+            Gen3SavePokemon::from_bytes(&mon, Gen3SaveLocation::Party { slot: 0 }),
+            None
+        );
+        assert_eq!(
+            Gen3SavePokemon::from_bytes(&[0; BOX_MON_LEN], Gen3SaveLocation::Party { slot: 0 }),
+            None
+        );
+    }
+
+    #[test]
+    fn reads_save() {
+        let party_mon = encode_mon(0xaabbccdd, (54321 << 16) | 12345, 1, 0);
+        let box_mon = encode_mon(0x11223344, 0, 280, 1 << 30);
+        let save = gen3_read_save(Gen3SaveOptions {
+            save: frlg_save(&party_mon, &box_mon),
+        })
+        .unwrap();
+
+        assert_eq!(save.version, Gen3SaveVersion::FireRedLeafGreen);
+        assert_eq!(save.trainer_name, "Red");
+        assert_eq!((save.tid, save.sid), (12345, 54321));
+        assert_eq!(save.rtc_offset, None);
+
+        assert_eq!(save.party.len(), 1);
+        assert_eq!(save.party[0].species, Species::Bulbasaur);
+        assert_eq!(save.party[0].pid, 0xaabbccdd);
+
+        assert_eq!(save.boxes.len(), 1);
+        assert_eq!(
+            save.boxes[0].location,
+            Gen3SaveLocation::Box {
+                box_index: 1,
+                slot: 1
+            }
+        );
+        assert_eq!(save.boxes[0].species, Species::Torchic);
+        assert!(save.boxes[0].is_egg);
+    }
+
+    #[test]
+    fn rejects_corrupted_save() {
+        let party_mon = encode_mon(0xaabbccdd, 0, 1, 0);
+        let mut save = frlg_save(&party_mon, &[0; BOX_MON_LEN]);
+        save[SECTOR_LEN + 0x38] ^= 1;
+
+        assert_eq!(
+            gen3_read_save(Gen3SaveOptions { save }),
+            Err(Sav3ReadError::InvalidChecksum)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_save() {
+        assert_eq!(
+            gen3_read_save(Gen3SaveOptions { save: vec![0; 10] }),
+            Err(Sav3ReadError::InvalidLength)
+        );
+    }
+}
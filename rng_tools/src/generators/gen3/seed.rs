@@ -1,23 +1,151 @@
 use crate::RngDateTime;
-use chrono::{Datelike, NaiveDate, Timelike};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
 
-pub fn calc_seed(datetime: &RngDateTime) -> Option<u16> {
-    let naive_datetime = datetime.to_naive_datetime()?;
-    let d = naive_datetime
-        .date()
+// Days count from 2000-01-01, but 2001 restarts the count as if 2000 never happened
+fn rtc_days(date: NaiveDate) -> Option<u32> {
+    let days = date
         .signed_duration_since(NaiveDate::from_ymd_opt(2000, 1, 1)?)
         .num_days() as u32
-        - if naive_datetime.year() > 2000 { 366 } else { 0 }
+        - if date.year() > 2000 { 366 } else { 0 }
         + 1;
+    Some(days)
+}
 
-    let h = naive_datetime.hour();
-    let m = naive_datetime.minute();
-
+fn seed_from_rtc(days: u32, hour: u32, minute: u32) -> u16 {
     let seed = 1440u32
-        .wrapping_mul(d)
-        .wrapping_add(960u32.wrapping_mul(h / 10))
-        .wrapping_add(60u32.wrapping_mul(h % 10))
-        .wrapping_add(16u32.wrapping_mul(m / 10))
-        .wrapping_add(m % 10);
-    Some(((seed >> 16) as u16) ^ (seed as u16))
+        .wrapping_mul(days)
+        .wrapping_add(960u32.wrapping_mul(hour / 10))
+        .wrapping_add(60u32.wrapping_mul(hour % 10))
+        .wrapping_add(16u32.wrapping_mul(minute / 10))
+        .wrapping_add(minute % 10);
+    ((seed >> 16) as u16) ^ (seed as u16)
+}
+
+pub fn calc_seed(datetime: &RngDateTime) -> Option<u16> {
+    let naive_datetime = datetime.to_naive_datetime()?;
+    let days = rtc_days(naive_datetime.date())?;
+    Some(seed_from_rtc(
+        days,
+        naive_datetime.hour(),
+        naive_datetime.minute(),
+    ))
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct SeedTime3 {
+    pub datetime: RngDateTime,
+    pub days: u32,
+    pub hour: u32,
+    pub minute: u32,
+    // How far the clock moves from the current time
+    pub adjustment_minutes: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct SeedTime3Options {
+    pub seed: u16,
+    pub min_year: u32,
+    pub max_year: u32,
+    pub current_datetime: RngDateTime,
+}
+
+fn adjustment_minutes(current: Option<NaiveDateTime>, target: NaiveDateTime) -> i64 {
+    current
+        .map(|current| target.signed_duration_since(current).num_minutes())
+        .unwrap_or_default()
+}
+
+// Seconds don't affect the seed, so every result is at the start of its minute
+#[wasm_bindgen]
+pub fn rs_calculate_seedtime(opts: SeedTime3Options) -> Vec<SeedTime3> {
+    let min_year = opts.min_year.clamp(2000, 2100) as i32;
+    let max_year = opts.max_year.clamp(2000, 2100) as i32;
+    let current = opts.current_datetime.to_naive_datetime();
+
+    let (Some(start), Some(end)) = (
+        NaiveDate::from_ymd_opt(min_year, 1, 1),
+        NaiveDate::from_ymd_opt(max_year, 12, 31),
+    ) else {
+        return vec![];
+    };
+
+    let mut results = vec![];
+    for date in start.iter_days().take_while(|date| *date <= end) {
+        let Some(days) = rtc_days(date) else {
+            continue;
+        };
+
+        for hour in 0..24 {
+            for minute in 0..60 {
+                if seed_from_rtc(days, hour, minute) != opts.seed {
+                    continue;
+                }
+
+                let Some(datetime) = date.and_hms_opt(hour, minute, 0) else {
+                    continue;
+                };
+                results.push(SeedTime3 {
+                    datetime: datetime.into(),
+                    days,
+                    hour,
+                    minute,
+                    adjustment_minutes: adjustment_minutes(current, datetime),
+                });
+            }
+        }
+    }
+
+    results.sort_by_key(|result| result.adjustment_minutes.abs());
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn seedtime_options(seed: u16) -> SeedTime3Options {
+        SeedTime3Options {
+            seed,
+            min_year: 2000,
+            max_year: 2001,
+            current_datetime: RngDateTime::new(2001, 6, 15, 12, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn finds_times_for_seed() {
+        let results = rs_calculate_seedtime(seedtime_options(0x1234));
+
+        assert!(!results.is_empty());
+        results.iter().for_each(|result| {
+            assert_eq!(calc_seed(&result.datetime), Some(0x1234));
+            assert_eq!(result.datetime.second, 0);
+        });
+    }
+
+    #[test]
+    fn round_trips_known_time() {
+        let datetime = RngDateTime::new(2000, 3, 4, 17, 38, 0).unwrap();
+        let seed = calc_seed(&datetime).unwrap();
+        let results = rs_calculate_seedtime(seedtime_options(seed));
+
+        assert!(results.iter().any(|result| result.datetime == datetime));
+    }
+
+    #[test]
+    fn ranks_by_least_adjustment() {
+        let results = rs_calculate_seedtime(seedtime_options(0x5a0));
+
+        assert!(
+            results
+                .windows(2)
+                .all(|results| results[0].adjustment_minutes.abs()
+                    <= results[1].adjustment_minutes.abs())
+        );
+    }
 }
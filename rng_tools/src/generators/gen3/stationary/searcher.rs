@@ -1,3 +1,4 @@
+use crate::gen3::RsTidSidOptions;
 use crate::gen3::util::recover_poke_rng_iv;
 use crate::rng::Rng;
 use crate::rng::lcrng::Pokerng;
@@ -17,6 +18,9 @@ pub struct Static3SearcherResult {
     pub gender: Gender,
     pub nature: Nature,
     pub shiny: bool,
+    // Only set when searching from initial seeds
+    pub initial_seed: Option<u32>,
+    pub advance: Option<usize>,
 }
 
 impl PkmState for Static3SearcherResult {
@@ -50,6 +54,57 @@ pub struct Static3SearcherOptions {
     pub bugged_roamer: bool,
     pub species: Species,
     pub filter: PkmFilter,
+    pub initial_seeds: Option<Static3InitialSeedOptions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Static3InitialSeeds {
+    Emerald,
+    Rs(RsTidSidOptions),
+    // Any seed the live battery clock can make
+    RsLiveBattery,
+    Frlg(Vec<u16>),
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Static3InitialSeedOptions {
+    pub seeds: Static3InitialSeeds,
+    pub max_advances: usize,
+}
+
+impl Static3InitialSeedOptions {
+    // Returns the closest initial seed and the advance that generates the seed
+    fn trace(&self, seed: u32) -> Option<(u32, usize)> {
+        let closest = |initial_seeds: &[u32]| {
+            initial_seeds
+                .iter()
+                .map(|&initial_seed| {
+                    let advance = Pokerng::new(initial_seed).distance_to(seed) as usize;
+                    (initial_seed, advance)
+                })
+                .min_by_key(|(_, advance)| *advance)
+        };
+
+        let (initial_seed, advance) = match &self.seeds {
+            Static3InitialSeeds::Emerald => closest(&[0]),
+            Static3InitialSeeds::Rs(rs_opts) => closest(&[rs_opts.seed() as u32]),
+            Static3InitialSeeds::Frlg(seeds) => {
+                closest(&seeds.iter().map(|&seed| seed as u32).collect::<Vec<_>>())
+            }
+            Static3InitialSeeds::RsLiveBattery => {
+                let mut rng = Pokerng::new(seed);
+                (0..=self.max_advances).find_map(|advance| {
+                    let initial_seed = rng.seed();
+                    rng.next_back();
+                    (initial_seed <= 0xffff).then_some((initial_seed, advance))
+                })
+            }
+        }?;
+
+        (advance <= self.max_advances).then_some((initial_seed, advance))
+    }
 }
 
 #[wasm_bindgen]
@@ -72,7 +127,7 @@ pub fn gen3_static_searcher_states(opts: &Static3SearcherOptions) -> Vec<Static3
         spe: max_spe,
     } = opts.filter.max_ivs;
 
-    iproduct!(
+    let mut results = iproduct!(
         min_hp..=max_hp,
         min_atk..=max_atk,
         min_def..=max_def,
@@ -93,7 +148,13 @@ pub fn gen3_static_searcher_states(opts: &Static3SearcherOptions) -> Vec<Static3
             opts,
         )
     })
-    .collect()
+    .collect::<Vec<Static3SearcherResult>>();
+
+    // Closest to the initial seed first
+    if opts.initial_seeds.is_some() {
+        results.sort_by_key(|result| result.advance);
+    }
+    results
 }
 
 fn search_gen3_static(mut ivs: Ivs, opts: &Static3SearcherOptions) -> Vec<Static3SearcherResult> {
@@ -113,14 +174,21 @@ fn search_gen3_static(mut ivs: Ivs, opts: &Static3SearcherOptions) -> Vec<Static
             let mut rng = Pokerng::new(seed).rev();
             let pid = ((rng.rand::<u16>() as u32) << 16) | (rng.rand::<u16>() as u32);
             let nature = Nature::from_pid(pid);
+            let seed = rng.rand::<u32>();
+            let trace = match &opts.initial_seeds {
+                Some(initial_seeds) => Some(initial_seeds.trace(seed)?),
+                None => None,
+            };
             let state = Static3SearcherResult {
-                seed: rng.rand::<u32>(),
+                seed,
                 pid,
                 ivs,
                 ability: AbilityType::from((pid & 1) as u8),
                 gender: opts.species.gender_from_pid(pid),
                 nature,
                 shiny: gen3_shiny(pid, opts.tid, opts.sid),
+                initial_seed: trace.map(|(initial_seed, _)| initial_seed),
+                advance: trace.map(|(_, advance)| advance),
             };
             if opts.filter.pass_filter_no_ivs(&state) {
                 Some(state)
@@ -134,7 +202,9 @@ fn search_gen3_static(mut ivs: Ivs, opts: &Static3SearcherOptions) -> Vec<Static
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::gen3::{Static3GeneratorOptions, gen3_static_generator_states};
+    use crate::gen3::{
+        Static3GeneratorOptions, Static3GeneratorResult, gen3_static_generator_states,
+    };
 
     const PERFECT_IVS: Ivs = Ivs {
         hp: 31,
@@ -162,6 +232,7 @@ mod test {
                 ability: None,
                 stats: None,
             },
+            initial_seeds: None,
         };
 
         let results = gen3_static_searcher_states(&opts);
@@ -219,6 +290,7 @@ mod test {
                 ability: None,
                 stats: None,
             },
+            initial_seeds: None,
         };
 
         let results = gen3_static_searcher_states(&opts);
@@ -276,6 +348,7 @@ mod test {
                 ability: None,
                 stats: None,
             },
+            initial_seeds: None,
         };
 
         let results = gen3_static_searcher_states(&opts);
@@ -319,4 +392,84 @@ mod test {
             assert_eq!(generated.ivs, result.ivs);
         })
     }
+
+    fn generated_at(seed: u32, advance: usize) -> Static3GeneratorResult {
+        let opts = Static3GeneratorOptions {
+            offset: 0,
+            initial_advances: advance,
+            max_advances: 0,
+            seed,
+            species: Species::Groudon,
+            bugged_roamer: false,
+            method4: false,
+            tid: 12345,
+            sid: 54321,
+            filter: PkmFilter::new_allow_all(),
+        };
+        gen3_static_generator_states(&opts)[0]
+    }
+
+    fn traced_options(ivs: Ivs, seeds: Static3InitialSeeds) -> Static3SearcherOptions {
+        Static3SearcherOptions {
+            species: Species::Groudon,
+            bugged_roamer: false,
+            method4: false,
+            tid: 12345,
+            sid: 54321,
+            filter: PkmFilter {
+                min_ivs: ivs,
+                max_ivs: ivs,
+                ..PkmFilter::new_allow_all()
+            },
+            initial_seeds: Some(Static3InitialSeedOptions {
+                seeds,
+                max_advances: 5000,
+            }),
+        }
+    }
+
+    #[test]
+    fn traces_to_initial_seeds() {
+        let emerald = generated_at(0, 1234);
+        let results =
+            gen3_static_searcher_states(&traced_options(emerald.ivs, Static3InitialSeeds::Emerald));
+        assert!(results.iter().any(|result| result.pid == emerald.pid
+            && result.initial_seed == Some(0)
+            && result.advance == Some(1234)));
+
+        let frlg = generated_at(0xbeef, 4000);
+        let results = gen3_static_searcher_states(&traced_options(
+            frlg.ivs,
+            Static3InitialSeeds::Frlg(vec![0x1111, 0xbeef]),
+        ));
+        assert!(results.iter().any(|result| result.pid == frlg.pid
+            && result.initial_seed == Some(0xbeef)
+            && result.advance == Some(4000)));
+
+        let live_battery = generated_at(0x4321, 321);
+        let results = gen3_static_searcher_states(&traced_options(
+            live_battery.ivs,
+            Static3InitialSeeds::RsLiveBattery,
+        ));
+        assert!(results.iter().any(|result| result.pid == live_battery.pid
+            && result.initial_seed == Some(0x4321)
+            && result.advance == Some(321)));
+    }
+
+    #[test]
+    fn filters_results_outside_window() {
+        let rs = generated_at(0x5a0, 6000);
+        let results = gen3_static_searcher_states(&traced_options(
+            rs.ivs,
+            Static3InitialSeeds::Rs(RsTidSidOptions::DeadBattery),
+        ));
+
+        assert!(results.iter().all(|result| result.pid != rs.pid));
+        assert!(results.iter().all(|result| result.advance <= Some(5000)));
+        assert!(
+            results
+                .windows(2)
+                .all(|results| results[0].advance <= results[1].advance)
+        );
+    }
 }
@@ -44,6 +44,16 @@ pub enum RsTidSidOptions {
     Seed(u16),
 }
 
+impl RsTidSidOptions {
+    pub fn seed(&self) -> u16 {
+        match self {
+            RsTidSidOptions::Seed(seed) => *seed,
+            RsTidSidOptions::DeadBattery => 0x5a0,
+            RsTidSidOptions::DateTime(datetime) => calc_seed(datetime).unwrap_or_default(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct Gen3TidSidResult {
@@ -73,12 +83,7 @@ pub fn gen3_tidsid_states(opts: &Gen3TidSidOptions) -> Vec<Gen3TidSidResult> {
                 .collect()
         }
         Gen3TidSidVersionOptions::Rs(ver_opts) => {
-            let seed = match ver_opts {
-                RsTidSidOptions::Seed(seed) => *seed,
-                RsTidSidOptions::DeadBattery => 0x5a0,
-                RsTidSidOptions::DateTime(datetime) => calc_seed(datetime).unwrap_or_default(),
-            };
-            StateIterator::new(Pokerng::new(seed as u32))
+            StateIterator::new(Pokerng::new(ver_opts.seed() as u32))
                 .skip(opts.offset)
                 .enumerate()
                 .skip(opts.initial_advances)
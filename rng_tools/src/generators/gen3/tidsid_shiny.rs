@@ -3,7 +3,7 @@ use crate::generators::gen3::{
 };
 use crate::rng::Rng;
 use crate::rng::lcrng::Pokerng;
-use crate::{gen3_psv, gen3_tsv};
+use crate::{GBA_HIT_DISTRIBUTION, gen3_psv, gen3_tsv};
 use serde::{Deserialize, Serialize};
 use tsify_next::Tsify;
 use wasm_bindgen::prelude::*;
@@ -43,36 +43,10 @@ pub struct Gen3NearbySid {
     pub earliest_shiny_adv: usize,
 }
 
-/// TIMING_DISTR[index] is the probability that when trying to get advance X, the actual hit frame is X + 4 - index
-/// ex: target is 1000, TIMING_DISTR is [4% chance that hit advance is 996, 8%: 997, 12%: 998, 16%: 999, 20%: 1000, 16%: 1001, 12%: 1002, 8%: 1003, 4%:1004]
-const TIMING_DISTR: [f64; 9] = {
-    let unnormalized_distr: [usize; 9] = [1, 2, 3, 4, 5, 4, 3, 2, 1];
-    let mut normalized_distr: [f64; 9] = [0f64; 9];
-
-    let mut sum: usize = 0;
-    let mut i = 0;
-    loop {
-        sum += unnormalized_distr[i];
-        i += 1;
-        if i >= 9 {
-            break;
-        }
-    }
-
-    let mut i = 0;
-    loop {
-        normalized_distr[i] = unnormalized_distr[i] as f64 / sum as f64;
-        i += 1;
-        if i >= 9 {
-            break;
-        }
-    }
-    normalized_distr
-};
-
 const EARLIEST_VALID_ADVANCE: usize = 650; // for RSE starter
 /// AVG_ATTEMPT_TO_HIT_TARGET (5) is hardcoded in the shiny starter guide.
-const AVG_ATTEMPT_TO_HIT_TARGET: f64 = 1f64 / TIMING_DISTR[(TIMING_DISTR.len() - 1) / 2];
+const AVG_ATTEMPT_TO_HIT_TARGET: f64 =
+    1f64 / GBA_HIT_DISTRIBUTION[(GBA_HIT_DISTRIBUTION.len() - 1) / 2];
 
 /// Main entry point.
 /// Given a seed (game), a target_tid_gen_adv, and the TID the player obtained in-game, returns avg_adv_to_determine_sid
@@ -223,7 +197,7 @@ fn calculate_avg_adv_for_nearby_sids_prob_by_adv(
     // I assume the player will attempt to do both sids simulatenously if their target advances are very close (+- 2 advances)
     let mut prob_by_adv: Vec<AvgAdvNearbySids> = vec![];
     for (i, nearby_sid) in nearby_sids.iter().enumerate() {
-        let prob1 = TIMING_DISTR[i];
+        let prob1 = GBA_HIT_DISTRIBUTION[i];
         let merge_with = prob_by_adv.iter_mut().find(|adv2| {
             adv2.earliest_shiny_adv
                 .abs_diff(nearby_sid.earliest_shiny_adv)
@@ -261,7 +235,7 @@ fn calculate_avg_adv_for_nearby_sids_prob_by_adv(
 /// Returns in average, how long (in advances) it will take to determine their SID.
 /// It assumes optimal planning and that in average, hitting a specific advance takes AVG_ATTEMPT_TO_HIT_TARGET attempts.
 /// earliest_shiny_advs_by_nearby_sid[i] is earliest_shiny_advs for sid obtained from hitting (target_tid_gen_adv - 4 + i)
-/// earliest_shiny_advs_by_nearby_sid contains TIMING_DISTR elements
+/// earliest_shiny_advs_by_nearby_sid contains GBA_HIT_DISTRIBUTION elements
 fn calculate_avg_adv_for_nearby_sids(nearby_sids: &[Gen3NearbySid]) -> usize {
     let prob_by_adv = calculate_avg_adv_for_nearby_sids_prob_by_adv(nearby_sids);
 
@@ -286,7 +260,7 @@ fn calculate_tidsid_shiny_result_for_all_tids(
                 earliest_shiny_advance_by_tsv,
                 target_tid_gen_adv,
                 tid,
-                TIMING_DISTR.len(),
+                GBA_HIT_DISTRIBUTION.len(),
             );
             let avg_adv_to_determine_sid = calculate_avg_adv_for_nearby_sids(&nearby_sids);
             Gen3TidSidShinyResult {
@@ -361,7 +335,7 @@ fn find_best_tid_gen_adv(seed: u32, tid_gen_adv_min: usize, tid_gen_adv_max: usi
         })
         .collect();
 
-    let mid = (TIMING_DISTR.len() - 1) / 2;
+    let mid = (GBA_HIT_DISTRIBUTION.len() - 1) / 2;
     struct Advs {
         pub tidsid_adv: usize,
         pub method1_adv: usize,
@@ -371,7 +345,7 @@ fn find_best_tid_gen_adv(seed: u32, tid_gen_adv_min: usize, tid_gen_adv_max: usi
         .enumerate()
         .map(|(i, _adv)| {
             let mut sum: f64 = 0f64;
-            for (j, prob) in TIMING_DISTR.iter().enumerate() {
+            for (j, prob) in GBA_HIT_DISTRIBUTION.iter().enumerate() {
                 let ideal_idx = i as i32 - mid as i32 + j as i32;
                 let idx = if ideal_idx < 0 {
                     0
@@ -440,7 +414,7 @@ mod test {
             &earliest_adv_by_tsv,
             1000,
             0,
-            TIMING_DISTR.len(),
+            GBA_HIT_DISTRIBUTION.len(),
         );
         assert_list_eq!(
             sids,
@@ -497,7 +471,7 @@ mod test {
             &earliest_adv_by_tsv,
             1000,
             11,
-            TIMING_DISTR.len(),
+            GBA_HIT_DISTRIBUTION.len(),
         );
 
         assert_list_eq!(
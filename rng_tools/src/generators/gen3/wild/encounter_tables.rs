@@ -0,0 +1,262 @@
+use crate::gen3::{EncounterSlot, Gen3EncounterType, Gen3Game, Gen3Lead};
+use crate::{GenderRatio, Species};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Gen3Map {
+    // Ruby, Sapphire and Emerald
+    Route101,
+    Route103,
+    Route111,
+    // FireRed and LeafGreen
+    PalletTown,
+    Route1,
+}
+
+// Maps missing from the encounter tables are searched by their gender ratio,
+// without species or levels
+#[derive(Clone, Debug, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Wild3Area {
+    Map(Gen3Map),
+    Unlisted {
+        gender_ratio: GenderRatio,
+        // Slots with the type pulled in by a Static or Magnet Pull lead
+        lead_type_slots: Vec<EncounterSlot>,
+    },
+}
+
+impl Wild3Area {
+    pub fn map(&self) -> Option<Gen3Map> {
+        match self {
+            Wild3Area::Map(map) => Some(*map),
+            Wild3Area::Unlisted { .. } => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Wild3Slot {
+    pub species: Species,
+    pub min_level: u8,
+    pub max_level: u8,
+}
+
+impl Wild3Slot {
+    const fn new(species: Species, min_level: u8, max_level: u8) -> Self {
+        Self {
+            species,
+            min_level,
+            max_level,
+        }
+    }
+
+    // The games swap the levels if the table lists them backwards
+    pub fn level_bounds(&self) -> (u8, u8) {
+        if self.max_level >= self.min_level {
+            (self.min_level, self.max_level)
+        } else {
+            (self.max_level, self.min_level)
+        }
+    }
+
+    pub fn level_from_rand(&self, rand: u16) -> u8 {
+        let (min, max) = self.level_bounds();
+        let range = (max - min) as u16 + 1;
+        min + (rand % range) as u8
+    }
+}
+
+const HOENN_ROUTE_101_LAND: [Wild3Slot; 12] = [
+    Wild3Slot::new(Species::Wurmple, 2, 2),
+    Wild3Slot::new(Species::Poochyena, 2, 2),
+    Wild3Slot::new(Species::Wurmple, 2, 2),
+    Wild3Slot::new(Species::Wurmple, 3, 3),
+    Wild3Slot::new(Species::Poochyena, 3, 3),
+    Wild3Slot::new(Species::Poochyena, 3, 3),
+    Wild3Slot::new(Species::Wurmple, 3, 3),
+    Wild3Slot::new(Species::Poochyena, 3, 3),
+    Wild3Slot::new(Species::Zigzagoon, 2, 2),
+    Wild3Slot::new(Species::Zigzagoon, 2, 2),
+    Wild3Slot::new(Species::Zigzagoon, 3, 3),
+    Wild3Slot::new(Species::Zigzagoon, 3, 3),
+];
+
+const HOENN_SEA_SURF: [Wild3Slot; 5] = [
+    Wild3Slot::new(Species::Tentacool, 5, 35),
+    Wild3Slot::new(Species::Wingull, 10, 30),
+    Wild3Slot::new(Species::Wingull, 15, 25),
+    Wild3Slot::new(Species::Wingull, 25, 30),
+    Wild3Slot::new(Species::Pelipper, 25, 30),
+];
+
+// The old rod uses the first 2 slots, the good rod the next 3, and the super rod the last 5
+const HOENN_ROUTE_103_FISHING: [Wild3Slot; 10] = [
+    Wild3Slot::new(Species::Magikarp, 5, 10),
+    Wild3Slot::new(Species::Tentacool, 5, 10),
+    Wild3Slot::new(Species::Magikarp, 10, 30),
+    Wild3Slot::new(Species::Tentacool, 10, 30),
+    Wild3Slot::new(Species::Wailmer, 10, 30),
+    Wild3Slot::new(Species::Wailmer, 25, 30),
+    Wild3Slot::new(Species::Sharpedo, 30, 35),
+    Wild3Slot::new(Species::Wailmer, 20, 25),
+    Wild3Slot::new(Species::Wailmer, 35, 40),
+    Wild3Slot::new(Species::Wailmer, 40, 45),
+];
+
+const HOENN_ROUTE_111_ROCK_SMASH: [Wild3Slot; 5] = [
+    Wild3Slot::new(Species::Geodude, 10, 15),
+    Wild3Slot::new(Species::Geodude, 5, 10),
+    Wild3Slot::new(Species::Geodude, 15, 20),
+    Wild3Slot::new(Species::Geodude, 15, 20),
+    Wild3Slot::new(Species::Geodude, 15, 20),
+];
+
+const KANTO_ROUTE_1_LAND: [Wild3Slot; 12] = [
+    Wild3Slot::new(Species::Pidgey, 3, 3),
+    Wild3Slot::new(Species::Rattata, 3, 3),
+    Wild3Slot::new(Species::Pidgey, 3, 3),
+    Wild3Slot::new(Species::Rattata, 3, 3),
+    Wild3Slot::new(Species::Pidgey, 2, 2),
+    Wild3Slot::new(Species::Rattata, 2, 2),
+    Wild3Slot::new(Species::Pidgey, 3, 3),
+    Wild3Slot::new(Species::Rattata, 3, 3),
+    Wild3Slot::new(Species::Pidgey, 4, 4),
+    Wild3Slot::new(Species::Rattata, 4, 4),
+    Wild3Slot::new(Species::Pidgey, 5, 5),
+    Wild3Slot::new(Species::Rattata, 4, 4),
+];
+
+const KANTO_SEA_SURF: [Wild3Slot; 5] = [
+    Wild3Slot::new(Species::Tentacool, 5, 10),
+    Wild3Slot::new(Species::Tentacool, 10, 20),
+    Wild3Slot::new(Species::Tentacool, 20, 30),
+    Wild3Slot::new(Species::Tentacool, 30, 35),
+    Wild3Slot::new(Species::Tentacool, 35, 40),
+];
+
+// Slots are indexed by EncounterSlot
+pub fn wild3_encounter_table(
+    game: Gen3Game,
+    map: Gen3Map,
+    encounter_type: Gen3EncounterType,
+) -> Option<&'static [Wild3Slot]> {
+    use Gen3EncounterType::*;
+    match (game.is_frlg(), map, encounter_type) {
+        (false, Gen3Map::Route101, Land) => Some(&HOENN_ROUTE_101_LAND),
+        (false, Gen3Map::Route103, Surf) => Some(&HOENN_SEA_SURF),
+        (false, Gen3Map::Route103, OldRod | GoodRod | SuperRod) => Some(&HOENN_ROUTE_103_FISHING),
+        (false, Gen3Map::Route111, RockSmash) => Some(&HOENN_ROUTE_111_ROCK_SMASH),
+        (true, Gen3Map::PalletTown, Surf) => Some(&KANTO_SEA_SURF),
+        (true, Gen3Map::Route1, Land) => Some(&KANTO_ROUTE_1_LAND),
+        _ => None,
+    }
+}
+
+// Slots with the type pulled in by a Static or Magnet Pull lead
+pub fn wild3_lead_type_slots(
+    table: &[Wild3Slot],
+    encounter_type: Gen3EncounterType,
+    lead: Gen3Lead,
+) -> Vec<EncounterSlot> {
+    EncounterSlot::thresholds(encounter_type)
+        .iter()
+        .zip(table)
+        .filter(|(_, wild_slot)| lead.pulls_species(wild_slot.species))
+        .map(|((slot, _), _)| *slot)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tables_fill_every_slot() {
+        [
+            (
+                Gen3Game::Emerald,
+                Gen3Map::Route101,
+                Gen3EncounterType::Land,
+            ),
+            (Gen3Game::Ruby, Gen3Map::Route103, Gen3EncounterType::Surf),
+            (
+                Gen3Game::Sapphire,
+                Gen3Map::Route103,
+                Gen3EncounterType::SuperRod,
+            ),
+            (
+                Gen3Game::Emerald,
+                Gen3Map::Route111,
+                Gen3EncounterType::RockSmash,
+            ),
+            (
+                Gen3Game::LeafGreen,
+                Gen3Map::PalletTown,
+                Gen3EncounterType::Surf,
+            ),
+            (Gen3Game::FireRed, Gen3Map::Route1, Gen3EncounterType::Land),
+        ]
+        .into_iter()
+        .for_each(|(game, map, encounter_type)| {
+            let table = wild3_encounter_table(game, map, encounter_type).unwrap();
+            EncounterSlot::thresholds(encounter_type)
+                .iter()
+                .for_each(|(slot, _)| assert!((*slot as usize) < table.len()));
+        });
+    }
+
+    #[test]
+    fn tables_are_per_game() {
+        assert_eq!(
+            wild3_encounter_table(
+                Gen3Game::FireRed,
+                Gen3Map::Route101,
+                Gen3EncounterType::Land
+            ),
+            None
+        );
+        assert_eq!(
+            wild3_encounter_table(Gen3Game::Emerald, Gen3Map::Route1, Gen3EncounterType::Land),
+            None
+        );
+    }
+
+    #[test]
+    fn rolls_levels() {
+        let slot = Wild3Slot::new(Species::Tentacool, 35, 5);
+        assert_eq!(slot.level_from_rand(0), 5);
+        assert_eq!(slot.level_from_rand(30), 35);
+        assert_eq!(slot.level_from_rand(31), 5);
+    }
+
+    #[test]
+    fn lead_type_slots_match_species_types() {
+        let table = [
+            Wild3Slot::new(Species::Tentacool, 5, 35),
+            Wild3Slot::new(Species::Magnemite, 5, 35),
+            Wild3Slot::new(Species::Chinchou, 5, 35),
+            Wild3Slot::new(Species::Skarmory, 5, 35),
+            Wild3Slot::new(Species::Wingull, 5, 35),
+        ];
+        assert_eq!(
+            wild3_lead_type_slots(&table, Gen3EncounterType::Surf, Gen3Lead::Static),
+            [EncounterSlot::Slot1, EncounterSlot::Slot2]
+        );
+        assert_eq!(
+            wild3_lead_type_slots(&table, Gen3EncounterType::Surf, Gen3Lead::MagnetPull),
+            [EncounterSlot::Slot1, EncounterSlot::Slot3]
+        );
+        assert_eq!(
+            wild3_lead_type_slots(
+                &HOENN_ROUTE_101_LAND,
+                Gen3EncounterType::Land,
+                Gen3Lead::Static
+            ),
+            []
+        );
+    }
+}
@@ -1,27 +1,38 @@
 use crate::Ivs;
 use crate::gen3::EncounterSlot;
+use crate::gen3::Gen3EncounterType;
 use crate::gen3::Gen3Lead;
 use crate::gen3::Gen3Method;
+use crate::gen3::{
+    Gen3Game, Gen3Map, Wild3Area, Wild3Slot, wild3_encounter_table, wild3_lead_type_slots,
+};
 use crate::rng::Rng;
 use crate::rng::lcrng::Pokerng;
-use crate::{AbilityType, Gender, GenderRatio, Nature, PkmFilter, gen3_shiny};
+use crate::{AbilityType, Gender, GenderRatio, Nature, PkmFilter, Species, gen3_shiny};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
 
 pub struct Wild3GeneratorOptions {
     pub advance: usize,
     pub tid: u16,
     pub sid: u16,
-    pub gender_ratio: GenderRatio,
+    pub game: Gen3Game,
     pub map_idx: usize,
+    pub area: Wild3Area,
+    pub encounter_type: Gen3EncounterType,
     pub encounter_slot: Option<Vec<EncounterSlot>>,
+    pub safari_zone: bool,
     pub method: Gen3Method,
-    pub synchronize: Option<Gen3Lead>,
+    pub lead: Option<Gen3Lead>,
     pub filter: PkmFilter,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct Wild3GeneratorResult {
     pub advance: usize,
     pub map_idx: usize,
+    pub map: Option<Gen3Map>,
     pub pid: u32,
     pub ivs: Ivs,
     pub ability: AbilityType,
@@ -30,28 +41,153 @@ pub struct Wild3GeneratorResult {
     pub shiny: bool,
 
     pub encounter_slot: EncounterSlot,
+    // Unknown for unlisted maps
+    pub species: Option<Species>,
+    pub level: Option<u8>,
     pub synch: bool,
 }
 
+impl Wild3GeneratorOptions {
+    // Leads that do nothing in this game don't consume rands
+    fn active_lead(&self) -> Option<Gen3Lead> {
+        self.lead.filter(|lead| lead.affects_game(self.game))
+    }
+
+    fn wild_slot(&self, encounter_slot: EncounterSlot) -> Option<&'static Wild3Slot> {
+        wild3_encounter_table(self.game, self.area.map()?, self.encounter_type)?
+            .get(encounter_slot as usize)
+    }
+
+    // Listed maps without this kind of encounter can't generate anything
+    fn gender_ratio(&self, wild_slot: Option<&Wild3Slot>) -> Option<GenderRatio> {
+        match (&self.area, wild_slot) {
+            (_, Some(wild_slot)) => Some(wild_slot.species.gender_ratio()),
+            (Wild3Area::Unlisted { gender_ratio, .. }, None) => Some(*gender_ratio),
+            (Wild3Area::Map(_), None) => None,
+        }
+    }
+
+    fn lead_type_slots(&self, lead: Gen3Lead) -> Vec<EncounterSlot> {
+        match &self.area {
+            Wild3Area::Map(map) => wild3_encounter_table(self.game, *map, self.encounter_type)
+                .map(|table| wild3_lead_type_slots(table, self.encounter_type, lead))
+                .unwrap_or_default(),
+            Wild3Area::Unlisted {
+                lead_type_slots, ..
+            } => lead_type_slots.clone(),
+        }
+    }
+}
+
+// pokeemerald passes LAND_WILD_COUNT to TryGetRandomWildMonIndexByType even for
+// water, so surf reads past its 5 slots. Those entries aren't modeled and never match.
+const LAND_WILD_COUNT: usize = 12;
+
+fn wild3_encounter_slot(rng: &mut Pokerng, opts: &Wild3GeneratorOptions) -> EncounterSlot {
+    let type_lead = opts.active_lead().filter(|lead| {
+        matches!(lead, Gen3Lead::Static | Gen3Lead::MagnetPull)
+            && matches!(
+                opts.encounter_type,
+                Gen3EncounterType::Land | Gen3EncounterType::Surf
+            )
+    });
+
+    if let Some(lead) = type_lead {
+        if rng.rand::<u16>() % 2 == 0 {
+            // The game falls back to a normal roll when none or all of the slots match
+            let type_slots = opts.lead_type_slots(lead);
+            if !type_slots.is_empty() && type_slots.len() != LAND_WILD_COUNT {
+                let index = rng.rand::<u16>() as usize % type_slots.len();
+                return type_slots[index];
+            }
+        }
+    }
+
+    let encounter_rand = ((rng.rand::<u32>() >> 16) % 100) as u8;
+    EncounterSlot::from_rand(encounter_rand, opts.encounter_type)
+}
+
+fn wild3_level(
+    rng: &mut Pokerng,
+    opts: &Wild3GeneratorOptions,
+    wild_slot: Option<&Wild3Slot>,
+) -> Option<u8> {
+    let level_rand = rng.rand::<u16>();
+
+    // Pressure has a 50% chance for the max level, otherwise lowers the roll by one
+    if opts.active_lead() == Some(Gen3Lead::Pressure) {
+        let max_level = rng.rand::<u16>() % 2 == 0;
+        return wild_slot.map(|wild_slot| {
+            let (min, max) = wild_slot.level_bounds();
+            let level = wild_slot.level_from_rand(level_rand);
+            match (max_level, level > min) {
+                (true, _) => max,
+                (false, true) => level - 1,
+                (false, false) => level,
+            }
+        });
+    }
+
+    wild_slot.map(|wild_slot| wild_slot.level_from_rand(level_rand))
+}
+
 pub fn generate_gen3_wild(
     rng: &mut Pokerng,
     opts: &Wild3GeneratorOptions,
 ) -> Option<Wild3GeneratorResult> {
-    let encounter_rand = ((rng.rand::<u32>() >> 16) % 100) as u8;
-    let encounter_slot = EncounterSlot::from_rand(encounter_rand);
+    let encounter_slot = wild3_encounter_slot(rng, opts);
 
     if !EncounterSlot::passes_filter(opts.encounter_slot.as_deref(), encounter_slot) {
         return None;
     }
-    rng.rand::<u32>(); // level
+
+    let wild_slot = opts.wild_slot(encounter_slot);
+    let gender_ratio = opts.gender_ratio(wild_slot)?;
+    let species = wild_slot.map(|wild_slot| wild_slot.species);
+    let level = wild3_level(rng, opts, wild_slot);
+
+    // Fishing skips the Intimidate check
+    let fishing = matches!(
+        opts.encounter_type,
+        Gen3EncounterType::OldRod | Gen3EncounterType::GoodRod | Gen3EncounterType::SuperRod
+    );
+    let intimidate_level = match opts.active_lead() {
+        Some(Gen3Lead::Intimidate(lead_level)) if !fishing => Some(lead_level),
+        _ => None,
+    };
+    if let Some(lead_level) = intimidate_level {
+        // Whether the roll happens depends on the level, so it can't be known without one
+        let level = level?;
+        if lead_level > 5 && level <= lead_level - 5 && rng.rand::<u16>() % 2 == 0 {
+            return None;
+        }
+    }
+
+    let lead = opts.active_lead();
+    let forced_gender = match lead {
+        Some(Gen3Lead::CuteCharm(lead_gender)) if gender_ratio.has_both_genders() => {
+            if rng.rand::<u16>() % 3 != 0 {
+                Some(match lead_gender {
+                    Gender::Female => Gender::Male,
+                    _ => Gender::Female,
+                })
+            } else {
+                None
+            }
+        }
+        _ => None,
+    };
+
+    // The Safari Zone rolls for a Pokéblock nature even when no Pokéblock is out.
+    // FRLG has no Pokéblocks, so it skips the roll.
+    if opts.safari_zone && !opts.game.is_frlg() {
+        rng.rand::<u32>();
+    }
 
     let nature_rand: u8;
     let mut is_synch = false;
 
-    match opts.synchronize {
-        None => {
-            nature_rand = (rng.rand::<u16>() % 25) as u8;
-        }
+    match lead {
         Some(Gen3Lead::Synchronize(lead_nature)) => {
             if (rng.rand::<u16>() & 1) == 0 {
                 nature_rand = lead_nature.into();
@@ -60,6 +196,9 @@ pub fn generate_gen3_wild(
                 nature_rand = (rng.rand::<u16>() % 25) as u8;
             }
         }
+        _ => {
+            nature_rand = (rng.rand::<u16>() % 25) as u8;
+        }
     };
 
     let mut pid: u32;
@@ -67,8 +206,14 @@ pub fn generate_gen3_wild(
         let pid_low = rng.rand::<u16>() as u32;
         let pid_high = rng.rand::<u16>() as u32;
         pid = (pid_high << 16) | pid_low;
-        if pid % 25 == nature_rand as u32 {
-            break;
+        if pid % 25 != nature_rand as u32 {
+            continue;
+        }
+
+        // Cute Charm rerolls the PID until the gender matches as well
+        match forced_gender {
+            Some(gender) if gender_ratio.gender((pid & 0xFF) as u8) != gender => continue,
+            _ => break,
         }
     }
 
@@ -85,7 +230,7 @@ pub fn generate_gen3_wild(
         }
     }
     let rate: u8 = (pid & 0xFF) as u8;
-    let gender = GenderRatio::gender(&opts.gender_ratio, rate);
+    let gender = gender_ratio.gender(rate);
     if let Some(wanted_gender) = opts.filter.gender {
         if gender != wanted_gender {
             return None;
@@ -134,7 +279,10 @@ pub fn generate_gen3_wild(
         nature,
         advance: opts.advance,
         map_idx: opts.map_idx,
+        map: opts.area.map(),
         encounter_slot,
+        species,
+        level,
         synch: is_synch,
     })
 }
@@ -2,24 +2,29 @@
 mod test {
     use crate::Ivs;
     use crate::gen3::EncounterSlot;
+    use crate::gen3::Gen3EncounterType;
     use crate::gen3::Gen3Lead;
     use crate::gen3::Gen3Method;
+    use crate::gen3::{Gen3Game, Gen3Map, Wild3Area, wild3_encounter_table};
     use crate::gen3::{Wild3GeneratorOptions, Wild3GeneratorResult, generate_gen3_wild};
     use crate::rng::Rng;
     use crate::rng::lcrng::Pokerng;
-    use crate::{AbilityType, Gender, GenderRatio, Nature, PkmFilter};
+    use crate::{AbilityType, Gender, GenderRatio, Nature, PkmFilter, Species};
 
     #[test]
     fn test_generate_wild3_no_filter() {
         let options = Wild3GeneratorOptions {
             tid: 0,
             sid: 0,
-            gender_ratio: GenderRatio::OneToOne,
+            encounter_type: Gen3EncounterType::Land,
+            game: Gen3Game::Emerald,
+            map_idx: 0,
+            area: Wild3Area::Map(Gen3Map::Route101),
+            safari_zone: false,
             encounter_slot: None,
             method: Gen3Method::H1,
             advance: 9,
-            map_idx: 0,
-            synchronize: None,
+            lead: None,
             filter: PkmFilter::new_allow_all(),
         };
 
@@ -29,7 +34,10 @@ mod test {
         let expected_result = Some(Wild3GeneratorResult {
             advance: 9,
             map_idx: 0,
+            map: Some(Gen3Map::Route101),
             encounter_slot: EncounterSlot::Slot1,
+            species: Some(Species::Poochyena),
+            level: Some(2),
             pid: 0x6E031C49,
             shiny: false,
             nature: Nature::Lax,
@@ -52,17 +60,20 @@ mod test {
     fn test_generate_wild3_with_filter() {
         let options = Wild3GeneratorOptions {
             advance: 908,
-            map_idx: 0,
             tid: 12345,
             sid: 54321,
-            gender_ratio: GenderRatio::OneToOne,
+            encounter_type: Gen3EncounterType::Land,
+            game: Gen3Game::Emerald,
+            map_idx: 0,
+            area: Wild3Area::Map(Gen3Map::Route101),
+            safari_zone: false,
             encounter_slot: Some(vec![
                 EncounterSlot::Slot0,
                 EncounterSlot::Slot6,
                 EncounterSlot::Slot8,
             ]),
             method: Gen3Method::H1,
-            synchronize: None,
+            lead: None,
             filter: PkmFilter {
                 shiny: false,
                 nature: Some(Nature::Adamant),
@@ -87,7 +98,10 @@ mod test {
         let expected_result = Some(Wild3GeneratorResult {
             advance: 908,
             map_idx: 0,
+            map: Some(Gen3Map::Route101),
             encounter_slot: EncounterSlot::Slot0,
+            species: Some(Species::Wurmple),
+            level: Some(2),
             pid: 0x02FA9E49,
             shiny: false,
             nature: Nature::Adamant,
@@ -110,12 +124,15 @@ mod test {
         let options = Wild3GeneratorOptions {
             tid: 34760,
             sid: 47362,
-            gender_ratio: GenderRatio::OneToOne,
+            encounter_type: Gen3EncounterType::Land,
+            game: Gen3Game::Emerald,
+            map_idx: 0,
+            area: Wild3Area::Map(Gen3Map::Route101),
+            safari_zone: false,
             encounter_slot: None,
             method: Gen3Method::H1,
             advance: 0,
-            map_idx: 0,
-            synchronize: None,
+            lead: None,
             filter: PkmFilter {
                 shiny: true,
                 nature: Some(Nature::Naive),
@@ -132,7 +149,10 @@ mod test {
         let expected_result = Some(Wild3GeneratorResult {
             advance: 0,
             map_idx: 0,
+            map: Some(Gen3Map::Route101),
             encounter_slot: EncounterSlot::Slot4,
+            species: Some(Species::Poochyena),
+            level: Some(3),
             pid: 0x692A57E1,
             shiny: true,
             nature: Nature::Naive,
@@ -156,12 +176,15 @@ mod test {
         let options = Wild3GeneratorOptions {
             tid: 12345,
             sid: 54321,
-            gender_ratio: GenderRatio::OneToOne,
+            encounter_type: Gen3EncounterType::Land,
+            game: Gen3Game::Emerald,
+            map_idx: 0,
+            area: Wild3Area::Map(Gen3Map::Route101),
+            safari_zone: false,
             encounter_slot: None,
             method: Gen3Method::H1,
             advance: 0,
-            map_idx: 0,
-            synchronize: Some(Gen3Lead::Synchronize(Nature::Hardy)),
+            lead: Some(Gen3Lead::Synchronize(Nature::Hardy)),
             filter: PkmFilter::new_allow_all(),
         };
 
@@ -170,7 +193,10 @@ mod test {
         let expected_result = Some(Wild3GeneratorResult {
             advance: 0,
             map_idx: 0,
+            map: Some(Gen3Map::Route101),
             encounter_slot: EncounterSlot::Slot4,
+            species: Some(Species::Poochyena),
+            level: Some(3),
             pid: 0x3A5DEC53,
             shiny: false,
             nature: Nature::Hardy,
@@ -188,4 +214,370 @@ mod test {
         });
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn test_encounter_type_slots() {
+        assert_eq!(
+            EncounterSlot::from_rand(94, Gen3EncounterType::Surf),
+            EncounterSlot::Slot2
+        );
+        assert_eq!(
+            EncounterSlot::from_rand(99, Gen3EncounterType::RockSmash),
+            EncounterSlot::Slot4
+        );
+        assert_eq!(
+            EncounterSlot::from_rand(69, Gen3EncounterType::OldRod),
+            EncounterSlot::Slot0
+        );
+        assert_eq!(
+            EncounterSlot::from_rand(70, Gen3EncounterType::OldRod),
+            EncounterSlot::Slot1
+        );
+        assert_eq!(
+            EncounterSlot::from_rand(0, Gen3EncounterType::GoodRod),
+            EncounterSlot::Slot2
+        );
+        assert_eq!(
+            EncounterSlot::from_rand(99, Gen3EncounterType::SuperRod),
+            EncounterSlot::Slot9
+        );
+    }
+
+    #[test]
+    fn test_generate_wild3_map_table() {
+        let options = Wild3GeneratorOptions {
+            tid: 0,
+            sid: 0,
+            game: Gen3Game::Emerald,
+            map_idx: 0,
+            area: Wild3Area::Map(Gen3Map::Route103),
+            encounter_type: Gen3EncounterType::Surf,
+            safari_zone: false,
+            encounter_slot: None,
+            method: Gen3Method::H1,
+            advance: 0,
+            lead: None,
+            filter: PkmFilter::new_allow_all(),
+        };
+
+        for seed in 0..100 {
+            let result = generate_gen3_wild(&mut Pokerng::new(seed), &options).unwrap();
+
+            let mut rng = Pokerng::new(seed);
+            let slot_rand = ((rng.rand::<u32>() >> 16) % 100) as u8;
+            let level_rand = rng.rand::<u16>();
+            let slot = EncounterSlot::from_rand(slot_rand, Gen3EncounterType::Surf);
+            let wild_slot = wild3_encounter_table(
+                Gen3Game::Emerald,
+                Gen3Map::Route103,
+                Gen3EncounterType::Surf,
+            )
+            .unwrap()[slot as usize];
+
+            assert_eq!(result.encounter_slot, slot);
+            assert_eq!(result.species, Some(wild_slot.species));
+            assert_eq!(result.level, Some(wild_slot.level_from_rand(level_rand)));
+            assert_ne!(result.gender, Gender::Genderless);
+        }
+    }
+
+    #[test]
+    fn test_generate_wild3_safari_zone() {
+        let mut options = Wild3GeneratorOptions {
+            tid: 0,
+            sid: 0,
+            encounter_type: Gen3EncounterType::Land,
+            game: Gen3Game::Emerald,
+            map_idx: 0,
+            area: Wild3Area::Map(Gen3Map::Route101),
+            safari_zone: true,
+            encounter_slot: None,
+            method: Gen3Method::H1,
+            advance: 0,
+            lead: None,
+            filter: PkmFilter::new_allow_all(),
+        };
+
+        let mut rng = Pokerng::new(0);
+        let safari = generate_gen3_wild(&mut rng, &options).unwrap();
+
+        // The Pokéblock roll shifts the nature rand by one
+        let mut rng = Pokerng::new(0);
+        rng.advance(3);
+        let nature = Nature::from((rng.rand::<u16>() % 25) as u8);
+        assert_eq!(safari.nature, nature);
+
+        options.safari_zone = false;
+        let mut rng = Pokerng::new(0);
+        let regular = generate_gen3_wild(&mut rng, &options).unwrap();
+        assert_eq!(safari.encounter_slot, regular.encounter_slot);
+    }
+
+    #[test]
+    fn test_generate_wild3_frlg_safari_zone() {
+        let mut options = Wild3GeneratorOptions {
+            tid: 0,
+            sid: 0,
+            encounter_type: Gen3EncounterType::Land,
+            game: Gen3Game::FireRed,
+            map_idx: 0,
+            area: Wild3Area::Map(Gen3Map::Route1),
+            safari_zone: true,
+            encounter_slot: None,
+            method: Gen3Method::H1,
+            advance: 0,
+            lead: None,
+            filter: PkmFilter::new_allow_all(),
+        };
+
+        // FRLG has no Pokéblock roll, so the Safari Zone changes nothing
+        let safari = generate_gen3_wild(&mut Pokerng::new(0), &options).unwrap();
+        options.safari_zone = false;
+        let regular = generate_gen3_wild(&mut Pokerng::new(0), &options).unwrap();
+
+        assert_eq!(safari, regular);
+        let mut rng = Pokerng::new(0);
+        rng.advance(2);
+        assert_eq!(safari.nature, Nature::from((rng.rand::<u16>() % 25) as u8));
+    }
+
+    fn lead_options(lead: Gen3Lead) -> Wild3GeneratorOptions {
+        Wild3GeneratorOptions {
+            tid: 0,
+            sid: 0,
+            encounter_type: Gen3EncounterType::Surf,
+            game: Gen3Game::Emerald,
+            map_idx: 0,
+            area: Wild3Area::Map(Gen3Map::Route103),
+            safari_zone: false,
+            encounter_slot: None,
+            method: Gen3Method::H1,
+            advance: 0,
+            lead: Some(lead),
+            filter: PkmFilter::new_allow_all(),
+        }
+    }
+
+    fn rands_from(seed: u32) -> Vec<u16> {
+        let mut rng = Pokerng::new(seed);
+        (0..4).map(|_| rng.rand::<u16>()).collect()
+    }
+
+    #[test]
+    fn test_generate_wild3_cute_charm() {
+        let options = lead_options(Gen3Lead::CuteCharm(Gender::Male));
+        for seed in 0..200 {
+            let result = generate_gen3_wild(&mut Pokerng::new(seed), &options).unwrap();
+            if rands_from(seed)[2] % 3 != 0 {
+                assert_eq!(result.gender, Gender::Female);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_wild3_pressure() {
+        let options = lead_options(Gen3Lead::Pressure);
+        for seed in 0..200 {
+            let result = generate_gen3_wild(&mut Pokerng::new(seed), &options).unwrap();
+            let rands = rands_from(seed);
+            let wild_slot = wild3_encounter_table(
+                Gen3Game::Emerald,
+                Gen3Map::Route103,
+                Gen3EncounterType::Surf,
+            )
+            .unwrap()[result.encounter_slot as usize];
+            let (min, max) = wild_slot.level_bounds();
+            let level = wild_slot.level_from_rand(rands[1]);
+            let expected = if rands[2] % 2 == 0 {
+                max
+            } else {
+                level.saturating_sub(1).max(min)
+            };
+            assert_eq!(result.level, Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_generate_wild3_static() {
+        let options = Wild3GeneratorOptions {
+            area: Wild3Area::Unlisted {
+                gender_ratio: GenderRatio::OneToOne,
+                lead_type_slots: vec![EncounterSlot::Slot3],
+            },
+            ..lead_options(Gen3Lead::Static)
+        };
+        for seed in 0..200 {
+            let result = generate_gen3_wild(&mut Pokerng::new(seed), &options).unwrap();
+            if rands_from(seed)[0] % 2 == 0 {
+                assert_eq!(result.encounter_slot, EncounterSlot::Slot3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_wild3_static_without_electric_types() {
+        // Route 103 has no Electric types, so the slot is rolled after the ability check
+        let options = lead_options(Gen3Lead::Static);
+        for seed in 0..200 {
+            let result = generate_gen3_wild(&mut Pokerng::new(seed), &options).unwrap();
+            let slot_rand = rands_from(seed)[1];
+            let expected =
+                EncounterSlot::from_rand((slot_rand % 100) as u8, options.encounter_type);
+            assert_eq!(result.encounter_slot, expected);
+        }
+    }
+
+    #[test]
+    fn test_generate_wild3_intimidate() {
+        let options = lead_options(Gen3Lead::Intimidate(50));
+        for seed in 0..200 {
+            let result = generate_gen3_wild(&mut Pokerng::new(seed), &options);
+            assert_eq!(result.is_none(), rands_from(seed)[2] % 2 == 0);
+        }
+
+        // Only leads at least 5 levels above the wild Pokémon repel it
+        let options = lead_options(Gen3Lead::Intimidate(9));
+        for seed in 0..200 {
+            assert!(generate_gen3_wild(&mut Pokerng::new(seed), &options).is_some());
+        }
+    }
+
+    #[test]
+    fn test_emerald_leads_do_nothing_elsewhere() {
+        for (game, map) in [
+            (Gen3Game::Ruby, Gen3Map::Route103),
+            (Gen3Game::FireRed, Gen3Map::PalletTown),
+        ] {
+            let no_lead = Wild3GeneratorOptions {
+                game,
+                area: Wild3Area::Map(map),
+                lead: None,
+                ..lead_options(Gen3Lead::Static)
+            };
+            for lead in [
+                Gen3Lead::CuteCharm(Gender::Male),
+                Gen3Lead::Pressure,
+                Gen3Lead::Static,
+                Gen3Lead::MagnetPull,
+                Gen3Lead::Intimidate(50),
+            ] {
+                let options = Wild3GeneratorOptions {
+                    game,
+                    area: Wild3Area::Map(map),
+                    ..lead_options(lead)
+                };
+                for seed in 0..50 {
+                    let expected = generate_gen3_wild(&mut Pokerng::new(seed), &no_lead);
+                    assert!(expected.is_some());
+                    assert_eq!(
+                        generate_gen3_wild(&mut Pokerng::new(seed), &options),
+                        expected
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fishing_skips_intimidate() {
+        let fishing = |lead| Wild3GeneratorOptions {
+            encounter_type: Gen3EncounterType::OldRod,
+            lead,
+            ..lead_options(Gen3Lead::Pressure)
+        };
+        let options = fishing(Some(Gen3Lead::Intimidate(50)));
+        let no_lead = fishing(None);
+        for seed in 0..50 {
+            let expected = generate_gen3_wild(&mut Pokerng::new(seed), &no_lead);
+            assert!(expected.is_some());
+            assert_eq!(
+                generate_gen3_wild(&mut Pokerng::new(seed), &options),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_wild3_rods_use_their_slots() {
+        let table = wild3_encounter_table(
+            Gen3Game::Emerald,
+            Gen3Map::Route103,
+            Gen3EncounterType::SuperRod,
+        )
+        .unwrap();
+        let options = Wild3GeneratorOptions {
+            encounter_type: Gen3EncounterType::SuperRod,
+            lead: None,
+            ..lead_options(Gen3Lead::Pressure)
+        };
+        for seed in 0..100 {
+            let result = generate_gen3_wild(&mut Pokerng::new(seed), &options).unwrap();
+            let slot = result.encounter_slot as usize;
+            assert!((5..10).contains(&slot));
+            assert_eq!(result.species, Some(table[slot].species));
+        }
+    }
+
+    #[test]
+    fn test_generate_wild3_needs_map_encounters() {
+        // Route 101 has no water
+        let options = Wild3GeneratorOptions {
+            map_idx: 0,
+            area: Wild3Area::Map(Gen3Map::Route101),
+            lead: None,
+            ..lead_options(Gen3Lead::Pressure)
+        };
+        for seed in 0..10 {
+            assert_eq!(generate_gen3_wild(&mut Pokerng::new(seed), &options), None);
+        }
+    }
+
+    #[test]
+    fn test_generate_wild3_unlisted_map() {
+        let route_101 = |area| Wild3GeneratorOptions {
+            encounter_type: Gen3EncounterType::Land,
+            area,
+            lead: None,
+            ..lead_options(Gen3Lead::Pressure)
+        };
+        let listed = route_101(Wild3Area::Map(Gen3Map::Route101));
+        // Poochyena, Wurmple and Zigzagoon are all one to one
+        let unlisted = Wild3GeneratorOptions {
+            map_idx: 1,
+            ..route_101(Wild3Area::Unlisted {
+                gender_ratio: GenderRatio::OneToOne,
+                lead_type_slots: vec![],
+            })
+        };
+        for seed in 0..50 {
+            let expected = generate_gen3_wild(&mut Pokerng::new(seed), &listed).map(|result| {
+                Wild3GeneratorResult {
+                    map_idx: 1,
+                    map: None,
+                    species: None,
+                    level: None,
+                    ..result
+                }
+            });
+            assert_eq!(
+                generate_gen3_wild(&mut Pokerng::new(seed), &unlisted),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_wild3_unlisted_map_intimidate() {
+        let options = Wild3GeneratorOptions {
+            encounter_type: Gen3EncounterType::Land,
+            area: Wild3Area::Unlisted {
+                gender_ratio: GenderRatio::OneToOne,
+                lead_type_slots: vec![],
+            },
+            ..lead_options(Gen3Lead::Intimidate(50))
+        };
+        for seed in 0..10 {
+            assert_eq!(generate_gen3_wild(&mut Pokerng::new(seed), &options), None);
+        }
+    }
 }
@@ -1,4 +1,5 @@
-use crate::Nature;
+use crate::gen3::Gen3Game;
+use crate::{Gender, Nature, Species};
 use serde::{Deserialize, Serialize};
 use tsify_next::Tsify;
 use wasm_bindgen::prelude::wasm_bindgen;
@@ -28,34 +29,63 @@ pub enum EncounterSlot {
     Slot11 = 11,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Gen3EncounterType {
+    Land,
+    Surf,
+    RockSmash,
+    OldRod,
+    GoodRod,
+    SuperRod,
+}
+
 impl EncounterSlot {
-    pub fn thresholds() -> &'static [(EncounterSlot, u8)] {
+    pub fn thresholds(encounter_type: Gen3EncounterType) -> &'static [(EncounterSlot, u8)] {
         use EncounterSlot::*;
-        &[
-            (Slot0, 20),
-            (Slot1, 40),
-            (Slot2, 50),
-            (Slot3, 60),
-            (Slot4, 70),
-            (Slot5, 80),
-            (Slot6, 85),
-            (Slot7, 90),
-            (Slot8, 94),
-            (Slot9, 98),
-            (Slot10, 99),
-            (Slot11, 100),
-        ]
+        match encounter_type {
+            Gen3EncounterType::Land => &[
+                (Slot0, 20),
+                (Slot1, 40),
+                (Slot2, 50),
+                (Slot3, 60),
+                (Slot4, 70),
+                (Slot5, 80),
+                (Slot6, 85),
+                (Slot7, 90),
+                (Slot8, 94),
+                (Slot9, 98),
+                (Slot10, 99),
+                (Slot11, 100),
+            ],
+            Gen3EncounterType::Surf | Gen3EncounterType::RockSmash => &[
+                (Slot0, 60),
+                (Slot1, 90),
+                (Slot2, 95),
+                (Slot3, 99),
+                (Slot4, 100),
+            ],
+            // Each rod only uses its own range of the 10 fishing slots
+            Gen3EncounterType::OldRod => &[(Slot0, 70), (Slot1, 100)],
+            Gen3EncounterType::GoodRod => &[(Slot2, 60), (Slot3, 80), (Slot4, 100)],
+            Gen3EncounterType::SuperRod => &[
+                (Slot5, 40),
+                (Slot6, 80),
+                (Slot7, 95),
+                (Slot8, 99),
+                (Slot9, 100),
+            ],
+        }
     }
-}
 
-impl EncounterSlot {
-    pub fn from_rand(rand: u8) -> Self {
-        for (slot, threshold) in Self::thresholds() {
+    pub fn from_rand(rand: u8, encounter_type: Gen3EncounterType) -> Self {
+        let thresholds = Self::thresholds(encounter_type);
+        for (slot, threshold) in thresholds {
             if rand < *threshold {
                 return *slot;
             }
         }
-        EncounterSlot::Slot0 // default to Slot0 if below first threshold
+        thresholds[0].0 // default to the first slot if above every threshold
     }
 
     pub fn passes_filter(filter: Option<&[EncounterSlot]>, actual: EncounterSlot) -> bool {
@@ -70,4 +100,70 @@ impl EncounterSlot {
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub enum Gen3Lead {
     Synchronize(Nature),
+    // Forces the opposite of the lead's gender, Emerald only
+    CuteCharm(Gender),
+    // Also Hustle and Vital Spirit, Emerald only
+    Pressure,
+    // Static and Magnet Pull pull in Electric and Steel types, Emerald only
+    Static,
+    MagnetPull,
+    // Also Keen Eye, holding the lead's level, Emerald only
+    Intimidate(u8),
+}
+
+impl Gen3Lead {
+    // Only Synchronize affects wild Pokémon outside of Emerald
+    pub fn affects_game(&self, game: Gen3Game) -> bool {
+        matches!(self, Gen3Lead::Synchronize(_)) || game == Gen3Game::Emerald
+    }
+
+    // Whether the species has the type Static or Magnet Pull pulls in
+    pub fn pulls_species(&self, species: Species) -> bool {
+        use Species::*;
+        match self {
+            Gen3Lead::Static => matches!(
+                species,
+                Pikachu
+                    | Raichu
+                    | Magnemite
+                    | Magneton
+                    | Voltorb
+                    | Electrode
+                    | Electabuzz
+                    | Jolteon
+                    | Zapdos
+                    | Pichu
+                    | Chinchou
+                    | Lanturn
+                    | Mareep
+                    | Flaaffy
+                    | Ampharos
+                    | Elekid
+                    | Raikou
+                    | Electrike
+                    | Manectric
+                    | Plusle
+                    | Minun
+            ),
+            Gen3Lead::MagnetPull => matches!(
+                species,
+                Magnemite
+                    | Magneton
+                    | Forretress
+                    | Steelix
+                    | Scizor
+                    | Skarmory
+                    | Mawile
+                    | Aron
+                    | Lairon
+                    | Aggron
+                    | Beldum
+                    | Metang
+                    | Metagross
+                    | Registeel
+                    | Jirachi
+            ),
+            _ => false,
+        }
+    }
 }
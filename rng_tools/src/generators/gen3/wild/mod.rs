@@ -1,9 +1,11 @@
+mod encounter_tables;
 mod generator;
 mod generator_tests;
 mod methods;
 mod searcher;
 mod searcher_tests;
 
+pub use encounter_tables::*;
 pub use generator::*;
 pub use methods::*;
 pub use searcher::*;
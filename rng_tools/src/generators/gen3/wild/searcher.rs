@@ -1,46 +1,85 @@
 use super::{Wild3GeneratorOptions, Wild3GeneratorResult, generate_gen3_wild};
 use crate::gen3::EncounterSlot;
+use crate::gen3::Gen3EncounterType;
 use crate::gen3::Gen3Lead;
 use crate::gen3::Gen3Method;
-use crate::rng::StateIterator;
+use crate::gen3::util::recover_poke_rng_iv;
+use crate::gen3::{Gen3Game, Wild3Area};
 use crate::rng::lcrng::Pokerng;
-use crate::{GenderRatio, PkmFilter};
+use crate::rng::{Rng, StateIterator};
+use crate::{Ivs, PkmFilter};
+use itertools::iproduct;
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
 
+// Slot, level, lead ability, Safari Zone and nature rands before the PID
+const MAX_RANDS_BEFORE_PID: usize = 8;
+const MAX_PID_REROLLS: usize = 256;
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Wild3SearchArea {
+    pub area: Wild3Area,
+    pub encounter_slots: Option<Vec<EncounterSlot>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct Wild3SearcherOptions {
     pub initial_seed: u32,
     pub tid: u16,
     pub sid: u16,
-    pub gender_ratio: GenderRatio,
     pub initial_advances: usize,
     pub max_advances: usize,
     pub filter: PkmFilter,
     pub leads: Vec<Option<Gen3Lead>>,
-    pub encounter_slots_by_map: Vec<Option<Vec<EncounterSlot>>>,
+    pub game: Gen3Game,
+    // Every map is searched with the same encounter type
+    pub areas: Vec<Wild3SearchArea>,
+    pub encounter_type: Gen3EncounterType,
+    pub safari_zone: bool,
     pub methods: Vec<Gen3Method>,
 }
 
+impl Wild3SearcherOptions {
+    fn generator_options(
+        &self,
+        advance: usize,
+        map_idx: usize,
+        lead: Option<Gen3Lead>,
+        method: Gen3Method,
+    ) -> Wild3GeneratorOptions {
+        Wild3GeneratorOptions {
+            tid: self.tid,
+            sid: self.sid,
+            advance,
+            game: self.game,
+            map_idx,
+            area: self.areas[map_idx].area.clone(),
+            encounter_type: self.encounter_type,
+            encounter_slot: self.areas[map_idx].encounter_slots.clone(),
+            safari_zone: self.safari_zone,
+            method,
+            lead,
+            filter: self.filter.clone(),
+        }
+    }
+}
+
 fn search_wild3_at_given_advance(
-    rng: &mut Pokerng,
+    rng: &Pokerng,
     advance: usize,
     opts: &Wild3SearcherOptions,
 ) -> Vec<Wild3GeneratorResult> {
     let mut results: Vec<Wild3GeneratorResult> = vec![];
     for lead in opts.leads.iter() {
-        for (map_idx, encounter_slots) in opts.encounter_slots_by_map.iter().enumerate() {
+        for map_idx in 0..opts.areas.len() {
             for method in opts.methods.iter() {
-                let gen_opts = Wild3GeneratorOptions {
-                    tid: opts.tid,
-                    sid: opts.sid,
-                    advance,
-                    map_idx,
-                    gender_ratio: opts.gender_ratio,
-                    encounter_slot: encounter_slots.clone(),
-                    method: *method,
-                    synchronize: *lead,
-                    filter: opts.filter.clone(),
-                };
-
-                if let Some(result) = generate_gen3_wild(rng, &gen_opts) {
+                let gen_opts = opts.generator_options(advance, map_idx, *lead, *method);
+
+                // Every combination starts from the same advance
+                if let Some(result) = generate_gen3_wild(&mut rng.clone(), &gen_opts) {
                     results.push(result)
                 }
             }
@@ -55,6 +94,134 @@ pub fn search_wild3(opts: &Wild3SearcherOptions) -> Vec<Wild3GeneratorResult> {
         .enumerate()
         .skip(opts.initial_advances)
         .take(opts.max_advances.wrapping_add(1))
-        .flat_map(|(adv, mut rng)| search_wild3_at_given_advance(&mut rng, adv, opts))
+        .flat_map(|(adv, rng)| search_wild3_at_given_advance(&rng, adv, opts))
         .collect::<Vec<Wild3GeneratorResult>>()
 }
+
+#[wasm_bindgen]
+pub fn gen3_wild_searcher_states(opts: &Wild3SearcherOptions) -> Vec<Wild3GeneratorResult> {
+    search_wild3(opts)
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Wild3SeedSearcherResult {
+    pub seed: u32,
+    pub method: Gen3Method,
+    pub lead: Option<Gen3Lead>,
+    pub state: Wild3GeneratorResult,
+}
+
+// Finds the seeds that encounter the filter IVs, ignoring initial_seed and the advance range
+#[wasm_bindgen]
+pub fn gen3_wild_seed_searcher_states(opts: &Wild3SearcherOptions) -> Vec<Wild3SeedSearcherResult> {
+    let Ivs {
+        hp: min_hp,
+        atk: min_atk,
+        def: min_def,
+        spa: min_spa,
+        spd: min_spd,
+        spe: min_spe,
+    } = opts.filter.min_ivs;
+
+    let Ivs {
+        hp: max_hp,
+        atk: max_atk,
+        def: max_def,
+        spa: max_spa,
+        spd: max_spd,
+        spe: max_spe,
+    } = opts.filter.max_ivs;
+
+    iproduct!(
+        min_hp..=max_hp,
+        min_atk..=max_atk,
+        min_def..=max_def,
+        min_spa..=max_spa,
+        min_spd..=max_spd,
+        min_spe..=max_spe
+    )
+    .flat_map(|(hp, atk, def, spa, spd, spe)| {
+        let ivs = Ivs {
+            hp,
+            atk,
+            def,
+            spa,
+            spd,
+            spe,
+        };
+        opts.methods
+            .iter()
+            .flat_map(move |&method| search_wild3_seeds(ivs, method, opts))
+    })
+    .collect()
+}
+
+fn search_wild3_seeds(
+    ivs: Ivs,
+    method: Gen3Method,
+    opts: &Wild3SearcherOptions,
+) -> Vec<Wild3SeedSearcherResult> {
+    let cute_charm = opts
+        .leads
+        .iter()
+        .any(|lead| matches!(lead, Some(Gen3Lead::CuteCharm(_))));
+    let mut results: Vec<Wild3SeedSearcherResult> = vec![];
+
+    for iv_seed in recover_poke_rng_iv(&ivs, method == Gen3Method::H4) {
+        let mut rng = Pokerng::new(iv_seed).rev();
+        if method == Gen3Method::H2 {
+            rng.rand::<u16>();
+        }
+        let pid = ((rng.rand::<u16>() as u32) << 16) | (rng.rand::<u16>() as u32);
+
+        // Earlier PID rolls were rerolled for the wrong nature, or the wrong gender with Cute Charm
+        for _ in 0..MAX_PID_REROLLS {
+            let mut seed_rng = rng.clone();
+            for _ in 0..MAX_RANDS_BEFORE_PID {
+                let seed = seed_rng.rand::<u32>();
+                results.extend(verify_wild3_seed(seed, pid, ivs, method, opts, &results));
+            }
+
+            let reroll_pid = ((rng.rand::<u16>() as u32) << 16) | (rng.rand::<u16>() as u32);
+            if reroll_pid % 25 == pid % 25 && !cute_charm {
+                break;
+            }
+        }
+    }
+
+    results
+}
+
+fn verify_wild3_seed(
+    seed: u32,
+    pid: u32,
+    ivs: Ivs,
+    method: Gen3Method,
+    opts: &Wild3SearcherOptions,
+    found: &[Wild3SeedSearcherResult],
+) -> Vec<Wild3SeedSearcherResult> {
+    let mut results: Vec<Wild3SeedSearcherResult> = vec![];
+    for lead in opts.leads.iter() {
+        for map_idx in 0..opts.areas.len() {
+            let gen_opts = opts.generator_options(0, map_idx, *lead, method);
+            let Some(state) = generate_gen3_wild(&mut Pokerng::new(seed), &gen_opts) else {
+                continue;
+            };
+            if state.pid != pid || state.ivs != ivs {
+                continue;
+            }
+
+            let result = Wild3SeedSearcherResult {
+                seed,
+                method,
+                lead: *lead,
+                state,
+            };
+            if !found.contains(&result) {
+                results.push(result);
+            }
+        }
+    }
+    results
+}
@@ -7,22 +7,32 @@ mod test {
 
     use crate::Ivs;
     use crate::gen3::EncounterSlot;
+    use crate::gen3::Gen3EncounterType;
+    use crate::gen3::Gen3Game;
     use crate::gen3::Gen3Lead;
+    use crate::gen3::Gen3Map;
     use crate::gen3::Gen3Method;
     use crate::gen3::search_wild3;
-    use crate::gen3::{Wild3GeneratorResult, Wild3SearcherOptions};
-    use crate::{AbilityType, Gender, GenderRatio, Nature, PkmFilter};
+    use crate::gen3::{Wild3Area, Wild3GeneratorResult, Wild3SearchArea, Wild3SearcherOptions};
+    use crate::gen3::{gen3_wild_seed_searcher_states, generate_gen3_wild};
+    use crate::rng::lcrng::Pokerng;
+    use crate::{AbilityType, Gender, GenderRatio, Nature, PkmFilter, Species};
 
     #[test]
     fn test_search_wild3_no_filter() {
         let options = Wild3SearcherOptions {
             initial_seed: 0,
+            game: Gen3Game::Emerald,
+            areas: vec![Wild3SearchArea {
+                area: Wild3Area::Map(Gen3Map::Route101),
+                encounter_slots: None,
+            }],
+            encounter_type: Gen3EncounterType::Land,
+            safari_zone: false,
             leads: vec![None],
-            encounter_slots_by_map: vec![None],
             methods: vec![Gen3Method::H1],
             tid: 0,
             sid: 0,
-            gender_ratio: GenderRatio::OneToOne,
             initial_advances: 0,
             max_advances: 9,
             filter: PkmFilter::new_allow_all(),
@@ -32,7 +42,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 0,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot0,
+                species: Some(Species::Wurmple),
+                level: Some(2),
                 pid: 0xFC3367DB,
                 shiny: false,
                 nature: Nature::Bold,
@@ -51,7 +64,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 1,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot5,
+                species: Some(Species::Poochyena),
+                level: Some(3),
                 pid: 0x60A1E414,
                 shiny: false,
                 nature: Nature::Calm,
@@ -70,7 +86,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 2,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot0,
+                species: Some(Species::Wurmple),
+                level: Some(2),
                 pid: 0x639E3D69,
                 shiny: false,
                 nature: Nature::Bashful,
@@ -89,7 +108,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 3,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot1,
+                species: Some(Species::Poochyena),
+                level: Some(2),
                 pid: 0xAD05863A,
                 shiny: false,
                 nature: Nature::Timid,
@@ -108,7 +130,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 4,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot0,
+                species: Some(Species::Wurmple),
+                level: Some(2),
                 pid: 0x945CE0C6,
                 shiny: false,
                 nature: Nature::Sassy,
@@ -127,7 +152,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 5,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot4,
+                species: Some(Species::Poochyena),
+                level: Some(3),
                 pid: 0x91785DD6,
                 shiny: false,
                 nature: Nature::Serious,
@@ -146,7 +174,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 6,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot9,
+                species: Some(Species::Zigzagoon),
+                level: Some(2),
                 pid: 0xDFC5706A,
                 shiny: false,
                 nature: Nature::Jolly,
@@ -165,7 +196,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 7,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot7,
+                species: Some(Species::Poochyena),
+                level: Some(3),
                 pid: 0x618D27A6,
                 shiny: false,
                 nature: Nature::Adamant,
@@ -184,7 +218,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 8,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot4,
+                species: Some(Species::Poochyena),
+                level: Some(3),
                 pid: 0x1692618D,
                 shiny: false,
                 nature: Nature::Docile,
@@ -203,7 +240,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 9,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot1,
+                species: Some(Species::Poochyena),
+                level: Some(2),
                 pid: 0x6E031C49,
                 shiny: false,
                 nature: Nature::Lax,
@@ -230,15 +270,20 @@ mod test {
             initial_seed: 0x346A4A45,
             tid: 12345,
             sid: 54321,
-            gender_ratio: GenderRatio::OneToOne,
-            encounter_slots_by_map: vec![Some(vec![
-                EncounterSlot::Slot0,
-                EncounterSlot::Slot6,
-                EncounterSlot::Slot8,
-            ])],
             methods: vec![Gen3Method::H1],
             initial_advances: 60,
             max_advances: 3625,
+            game: Gen3Game::Emerald,
+            areas: vec![Wild3SearchArea {
+                area: Wild3Area::Map(Gen3Map::Route101),
+                encounter_slots: Some(vec![
+                    EncounterSlot::Slot0,
+                    EncounterSlot::Slot6,
+                    EncounterSlot::Slot8,
+                ]),
+            }],
+            encounter_type: Gen3EncounterType::Land,
+            safari_zone: false,
             leads: vec![None],
             filter: PkmFilter {
                 shiny: false,
@@ -261,7 +306,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 908,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot0,
+                species: Some(Species::Wurmple),
+                level: Some(2),
                 pid: 0x02FA9E49,
                 shiny: false,
                 nature: Nature::Adamant,
@@ -280,7 +328,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 3543,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot0,
+                species: Some(Species::Wurmple),
+                level: Some(2),
                 pid: 0xA44D455D,
                 shiny: false,
                 nature: Nature::Adamant,
@@ -299,7 +350,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 3577,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot6,
+                species: Some(Species::Wurmple),
+                level: Some(3),
                 pid: 0xA44D455D,
                 shiny: false,
                 nature: Nature::Adamant,
@@ -318,7 +372,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 3621,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot8,
+                species: Some(Species::Zigzagoon),
+                level: Some(2),
                 pid: 0xA44D455D,
                 shiny: false,
                 nature: Nature::Adamant,
@@ -345,11 +402,16 @@ mod test {
             initial_seed: 0x14a22065,
             tid: 34760,
             sid: 47362,
-            gender_ratio: GenderRatio::OneToOne,
-            encounter_slots_by_map: vec![None],
             methods: vec![Gen3Method::H1],
             initial_advances: 0,
             max_advances: 10,
+            game: Gen3Game::Emerald,
+            areas: vec![Wild3SearchArea {
+                area: Wild3Area::Map(Gen3Map::Route101),
+                encounter_slots: None,
+            }],
+            encounter_type: Gen3EncounterType::Land,
+            safari_zone: false,
             leads: vec![None],
             filter: PkmFilter {
                 shiny: true,
@@ -364,7 +426,10 @@ mod test {
         let expected_results = [Wild3GeneratorResult {
             advance: 0,
             map_idx: 0,
+            map: Some(Gen3Map::Route101),
             encounter_slot: EncounterSlot::Slot4,
+            species: Some(Species::Poochyena),
+            level: Some(3),
             pid: 0x692A57E1,
             shiny: true,
             nature: Nature::Naive,
@@ -390,11 +455,16 @@ mod test {
             initial_seed: 0x14a22065,
             tid: 12345,
             sid: 54321,
-            gender_ratio: GenderRatio::OneToOne,
-            encounter_slots_by_map: vec![None],
             methods: vec![Gen3Method::H1],
             initial_advances: 0,
             max_advances: 4,
+            game: Gen3Game::Emerald,
+            areas: vec![Wild3SearchArea {
+                area: Wild3Area::Map(Gen3Map::Route101),
+                encounter_slots: None,
+            }],
+            encounter_type: Gen3EncounterType::Land,
+            safari_zone: false,
             leads: vec![Some(Gen3Lead::Synchronize(Nature::Hardy))],
             filter: PkmFilter::new_allow_all(),
         };
@@ -402,7 +472,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 0,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot4,
+                species: Some(Species::Poochyena),
+                level: Some(3),
                 pid: 0x3A5DEC53,
                 shiny: false,
                 nature: Nature::Hardy,
@@ -421,7 +494,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 1,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot9,
+                species: Some(Species::Zigzagoon),
+                level: Some(2),
                 pid: 0x95BC176C,
                 shiny: false,
                 nature: Nature::Careful,
@@ -440,7 +516,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 2,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot7,
+                species: Some(Species::Poochyena),
+                level: Some(3),
                 pid: 0x7697C055,
                 shiny: false,
                 nature: Nature::Hasty,
@@ -459,7 +538,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 3,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot1,
+                species: Some(Species::Poochyena),
+                level: Some(2),
                 pid: 0x3A5DEC53,
                 shiny: false,
                 nature: Nature::Hardy,
@@ -478,7 +560,10 @@ mod test {
             Wild3GeneratorResult {
                 advance: 4,
                 map_idx: 0,
+                map: Some(Gen3Map::Route101),
                 encounter_slot: EncounterSlot::Slot5,
+                species: Some(Species::Poochyena),
+                level: Some(3),
                 pid: 0x57E115F6,
                 shiny: false,
                 nature: Nature::Naive,
@@ -498,4 +583,134 @@ mod test {
         let result = search_wild3(&options);
         assert_list_eq!(result, expected_results);
     }
+
+    fn seed_search_options(
+        leads: Vec<Option<Gen3Lead>>,
+        methods: Vec<Gen3Method>,
+    ) -> Wild3SearcherOptions {
+        Wild3SearcherOptions {
+            initial_seed: 0,
+            game: Gen3Game::Emerald,
+            areas: vec![Wild3SearchArea {
+                area: Wild3Area::Map(Gen3Map::Route101),
+                encounter_slots: None,
+            }],
+            encounter_type: Gen3EncounterType::Land,
+            safari_zone: false,
+            leads,
+            methods,
+            tid: 0,
+            sid: 0,
+            initial_advances: 0,
+            max_advances: 0,
+            filter: PkmFilter::new_allow_all(),
+        }
+    }
+
+    #[test]
+    fn test_search_wild3_clones_rng_per_combination() {
+        let combined = seed_search_options(
+            vec![None, Some(Gen3Lead::Synchronize(Nature::Adamant))],
+            vec![Gen3Method::H1, Gen3Method::H2, Gen3Method::H4],
+        );
+        let results = search_wild3(&Wild3SearcherOptions {
+            initial_seed: 0x1234,
+            ..combined.clone()
+        });
+
+        let mut expected: Vec<Wild3GeneratorResult> = vec![];
+        for lead in combined.leads.iter() {
+            for method in combined.methods.iter() {
+                expected.extend(search_wild3(&Wild3SearcherOptions {
+                    initial_seed: 0x1234,
+                    leads: vec![*lead],
+                    methods: vec![*method],
+                    ..combined.clone()
+                }));
+            }
+        }
+
+        assert_eq!(results.len(), 6);
+        assert_list_eq!(results, expected);
+    }
+
+    #[test]
+    fn test_search_wild3_slots_per_area() {
+        let options = Wild3SearcherOptions {
+            initial_seed: 0x1234,
+            max_advances: 50,
+            areas: vec![
+                Wild3SearchArea {
+                    area: Wild3Area::Map(Gen3Map::Route101),
+                    encounter_slots: Some(vec![EncounterSlot::Slot0]),
+                },
+                Wild3SearchArea {
+                    area: Wild3Area::Unlisted {
+                        gender_ratio: GenderRatio::OneToOne,
+                        lead_type_slots: vec![],
+                    },
+                    encounter_slots: Some(vec![EncounterSlot::Slot1]),
+                },
+            ],
+            ..seed_search_options(vec![None], vec![Gen3Method::H1])
+        };
+        let results = search_wild3(&options);
+
+        assert!(results.iter().any(|result| result.map_idx == 0));
+        assert!(results.iter().any(|result| result.map_idx == 1));
+        for result in results {
+            match result.map_idx {
+                0 => {
+                    assert_eq!(result.encounter_slot, EncounterSlot::Slot0);
+                    assert_eq!(result.map, Some(Gen3Map::Route101));
+                }
+                _ => {
+                    assert_eq!(result.encounter_slot, EncounterSlot::Slot1);
+                    assert_eq!(result.map, None);
+                    assert_eq!(result.species, None);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_search_wild3_seeds_from_ivs() {
+        for (method, lead) in [
+            (Gen3Method::H1, None),
+            (Gen3Method::H2, Some(Gen3Lead::Synchronize(Nature::Timid))),
+            (Gen3Method::H4, Some(Gen3Lead::CuteCharm(Gender::Female))),
+        ] {
+            let seed = 0xBEEF1234;
+            let mut options = seed_search_options(vec![lead], vec![method]);
+            let gen_opts = crate::gen3::Wild3GeneratorOptions {
+                advance: 0,
+                tid: 0,
+                sid: 0,
+                game: Gen3Game::Emerald,
+                map_idx: 0,
+                area: Wild3Area::Map(Gen3Map::Route101),
+                encounter_type: Gen3EncounterType::Land,
+                encounter_slot: None,
+                safari_zone: false,
+                method,
+                lead,
+                filter: PkmFilter::new_allow_all(),
+            };
+            let expected = generate_gen3_wild(&mut Pokerng::new(seed), &gen_opts).unwrap();
+            options.filter.min_ivs = expected.ivs;
+            options.filter.max_ivs = expected.ivs;
+
+            let results = gen3_wild_seed_searcher_states(&options);
+
+            assert!(results.iter().any(|result| result.seed == seed
+                && result.method == method
+                && result.lead == lead
+                && result.state == expected));
+            assert!(
+                results
+                    .iter()
+                    .all(|result| result.state.ivs == expected.ivs)
+            );
+        }
+    }
 }
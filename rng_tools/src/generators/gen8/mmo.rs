@@ -0,0 +1,468 @@
+use crate::rng::Rng;
+use crate::rng::xoroshiro::Xoroshiro;
+use crate::{AbilityType, G6Idx, Gender, GenderRatio, Ivs, Nature, PkmFilter, PkmState, Species};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+const MAX_ALIVE: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum LaResearchLevel {
+    Incomplete,
+    Complete,
+    Perfect,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum LaOutbreakType {
+    Mass,
+    MassiveMass,
+}
+
+pub fn la_shiny_rolls(
+    outbreak: LaOutbreakType,
+    research: LaResearchLevel,
+    shiny_charm: bool,
+) -> u8 {
+    let research_rolls = match research {
+        LaResearchLevel::Incomplete => 0,
+        LaResearchLevel::Complete => 1,
+        LaResearchLevel::Perfect => 3,
+    };
+    let outbreak_rolls = match outbreak {
+        LaOutbreakType::Mass => 25,
+        LaOutbreakType::MassiveMass => 12,
+    };
+    let charm_rolls = if shiny_charm { 3 } else { 0 };
+
+    1 + research_rolls + outbreak_rolls + charm_rolls
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct MmoSlot {
+    pub species: Species,
+    pub rate: f32,
+    pub alpha: bool,
+    pub min_level: u8,
+    pub max_level: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum MmoAction {
+    Defeat { count: u8, ghosts: u8 },
+    StartBonus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct MmoPokemon {
+    pub species: Species,
+    pub level: u8,
+    pub alpha: bool,
+    pub ec: u32,
+    pub pid: u32,
+    pub shiny: bool,
+    pub shiny_roll: u8,
+    pub ivs: Ivs,
+    pub ability: AbilityType,
+    pub gender: Gender,
+    pub nature: Nature,
+}
+
+impl PkmState for MmoPokemon {
+    fn shiny(&self) -> bool {
+        self.shiny
+    }
+
+    fn nature(&self) -> Nature {
+        self.nature
+    }
+
+    fn ivs(&self) -> &Ivs {
+        &self.ivs
+    }
+
+    fn ability(&self) -> AbilityType {
+        self.ability
+    }
+
+    fn gender(&self) -> Gender {
+        self.gender
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+pub struct MmoResult {
+    pub path: Vec<MmoAction>,
+    pub bonus: bool,
+    pub spawn_index: usize,
+    pub gen_seed: u64,
+    pub pokemon: MmoPokemon,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+pub struct MmoOptions {
+    pub group_seed: u64,
+    pub outbreak: LaOutbreakType,
+    pub research: LaResearchLevel,
+    pub shiny_charm: bool,
+    pub base_table: Vec<MmoSlot>,
+    pub base_count: usize,
+    pub bonus_table: Vec<MmoSlot>,
+    pub bonus_count: usize,
+    pub max_actions: usize,
+    pub filter: PkmFilter,
+}
+
+fn generate_mmo_pokemon(gen_seed: u64, slot: &MmoSlot, level: u8, shiny_rolls: u8) -> MmoPokemon {
+    let mut rng = Xoroshiro::new(gen_seed);
+    let ec = rng.rand::<u32>();
+    let fake_tid = rng.rand::<u32>();
+
+    let mut pid = 0;
+    let mut shiny = false;
+    let mut shiny_roll = 0;
+    for roll in 1..=shiny_rolls {
+        pid = rng.rand::<u32>();
+        shiny_roll = roll;
+        let xor = (pid >> 16) ^ (pid & 0xffff) ^ (fake_tid >> 16) ^ (fake_tid & 0xffff);
+        if xor < 16 {
+            shiny = true;
+            break;
+        }
+    }
+
+    let mut ivs = [None; 6];
+    let flawless_count = if slot.alpha { 3 } else { 0 };
+    for _ in 0..flawless_count {
+        loop {
+            let index = rng.rand_max::<u32>(6) as usize;
+            if ivs[index].is_none() {
+                ivs[index] = Some(31);
+                break;
+            }
+        }
+    }
+
+    let mut result_ivs = Ivs::default();
+    for (index, iv) in ivs.into_iter().enumerate() {
+        result_ivs[G6Idx::from(index as u8)] = match iv {
+            Some(iv) => iv,
+            None => rng.rand_max::<u32>(32) as u8,
+        };
+    }
+
+    let ability = AbilityType::from(rng.rand_max::<u32>(2) as u8);
+    let gender = match slot.species.gender_ratio() {
+        GenderRatio::Genderless => Gender::Genderless,
+        GenderRatio::FemaleOnly => Gender::Female,
+        GenderRatio::MaleOnly => Gender::Male,
+        ratio => {
+            if (rng.rand_max::<u32>(252) + 1) < ratio as u32 {
+                Gender::Female
+            } else {
+                Gender::Male
+            }
+        }
+    };
+    let nature = Nature::from(rng.rand_max::<u32>(25) as u8);
+
+    MmoPokemon {
+        species: slot.species,
+        level,
+        alpha: slot.alpha,
+        ec,
+        pid,
+        shiny,
+        shiny_roll,
+        ivs: result_ivs,
+        ability,
+        gender,
+        nature,
+    }
+}
+
+fn pick_slot(table: &[MmoSlot], roll: f32) -> Option<&MmoSlot> {
+    let mut roll = roll;
+    for slot in table {
+        if roll < slot.rate {
+            return Some(slot);
+        }
+        roll -= slot.rate;
+    }
+
+    table.last()
+}
+
+fn generate_spawn(
+    spawner_seed: u64,
+    table: &[MmoSlot],
+    shiny_rolls: u8,
+) -> Option<(u64, MmoPokemon)> {
+    let mut rng = Xoroshiro::new(spawner_seed);
+    let rate_sum = table.iter().map(|slot| slot.rate).sum::<f32>();
+    let slot = pick_slot(table, rng.next_float(rate_sum))?;
+    let gen_seed = rng.rand::<u64>();
+
+    let level_range = slot.max_level.saturating_sub(slot.min_level);
+    let level = if level_range == 0 {
+        slot.min_level
+    } else {
+        slot.min_level + rng.rand_max::<u32>(level_range as u32 + 1) as u8
+    };
+
+    Some((
+        gen_seed,
+        generate_mmo_pokemon(gen_seed, slot, level, shiny_rolls),
+    ))
+}
+
+#[derive(Debug, Clone)]
+struct MmoWave<'a> {
+    seed: u64,
+    alive: usize,
+    remaining: usize,
+    spawned: usize,
+    bonus: bool,
+    table: &'a [MmoSlot],
+}
+
+struct MmoSearch<'a> {
+    opts: &'a MmoOptions,
+    shiny_rolls: u8,
+    results: Vec<MmoResult>,
+}
+
+impl MmoSearch<'_> {
+    // Spawns `count` Pokemon (including ghosts) and moves the wave to the seed of the next respawn
+    fn spawn(&mut self, wave: &mut MmoWave, count: usize, path: &[MmoAction]) {
+        let mut rng = Xoroshiro::new(wave.seed);
+        for index in 0..count {
+            let spawner_seed = rng.rand::<u64>();
+            rng.next();
+
+            if index >= wave.remaining {
+                continue;
+            }
+
+            let spawn_index = wave.spawned + index;
+            if let Some((gen_seed, pokemon)) =
+                generate_spawn(spawner_seed, wave.table, self.shiny_rolls)
+            {
+                if self.opts.filter.pass_filter(&pokemon) {
+                    self.results.push(MmoResult {
+                        path: path.to_vec(),
+                        bonus: wave.bonus,
+                        spawn_index,
+                        gen_seed,
+                        pokemon,
+                    });
+                }
+            }
+        }
+
+        let real_spawns = count.min(wave.remaining);
+        wave.alive += real_spawns;
+        wave.remaining -= real_spawns;
+        wave.spawned += real_spawns;
+        wave.seed = rng.rand::<u64>();
+    }
+
+    fn start_bonus(&mut self, seed: u64, path: &mut Vec<MmoAction>) {
+        let mut wave = MmoWave {
+            seed,
+            alive: 0,
+            remaining: self.opts.bonus_count,
+            spawned: 0,
+            bonus: true,
+            table: &self.opts.bonus_table,
+        };
+
+        path.push(MmoAction::StartBonus);
+        let count = MAX_ALIVE.min(wave.remaining);
+        self.spawn(&mut wave, count, path);
+        self.permute(&wave, path);
+        path.pop();
+    }
+
+    fn permute(&mut self, wave: &MmoWave, path: &mut Vec<MmoAction>) {
+        if path.len() >= self.opts.max_actions {
+            return;
+        }
+
+        if wave.alive == 0 {
+            if !wave.bonus && !self.opts.bonus_table.is_empty() && self.opts.bonus_count > 0 {
+                self.start_bonus(wave.seed, path);
+            }
+            return;
+        }
+
+        for count in 1..=wave.alive {
+            let mut next_wave = wave.clone();
+            next_wave.alive -= count;
+
+            let ghosts = count.saturating_sub(next_wave.remaining);
+            path.push(MmoAction::Defeat {
+                count: count as u8,
+                ghosts: ghosts as u8,
+            });
+            if next_wave.remaining > 0 {
+                self.spawn(&mut next_wave, count, path);
+            }
+            self.permute(&next_wave, path);
+            path.pop();
+        }
+    }
+}
+
+#[wasm_bindgen]
+pub fn la_generate_mmo_paths(opts: &MmoOptions) -> Vec<MmoResult> {
+    let mut search = MmoSearch {
+        opts,
+        shiny_rolls: la_shiny_rolls(opts.outbreak, opts.research, opts.shiny_charm),
+        results: vec![],
+    };
+    let mut wave = MmoWave {
+        seed: opts.group_seed,
+        alive: 0,
+        remaining: opts.base_count,
+        spawned: 0,
+        bonus: false,
+        table: &opts.base_table,
+    };
+    let mut path = vec![];
+
+    let count = MAX_ALIVE.min(wave.remaining);
+    search.spawn(&mut wave, count, &path);
+    search.permute(&wave, &mut path);
+
+    // Several paths can reach the same spawn, so only keep the shortest one
+    let mut results = search.results;
+    results.sort_by_key(|result| (result.path.len(), result.bonus));
+    let mut unique: Vec<MmoResult> = vec![];
+    for result in results {
+        let seen = unique.iter().any(|found| {
+            found.gen_seed == result.gen_seed
+                && found.bonus == result.bonus
+                && found.spawn_index == result.spawn_index
+        });
+        if !seen {
+            unique.push(result);
+        }
+    }
+
+    unique
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn slot(species: Species, rate: f32, alpha: bool) -> MmoSlot {
+        MmoSlot {
+            species,
+            rate,
+            alpha,
+            min_level: 20,
+            max_level: 25,
+        }
+    }
+
+    fn options() -> MmoOptions {
+        MmoOptions {
+            group_seed: 0x1234567890abcdef,
+            outbreak: LaOutbreakType::MassiveMass,
+            research: LaResearchLevel::Perfect,
+            shiny_charm: true,
+            base_table: vec![
+                slot(Species::Wyrdeer, 100.0, false),
+                slot(Species::Wyrdeer, 2.0, true),
+            ],
+            base_count: 10,
+            bonus_table: vec![slot(Species::Kleavor, 100.0, false)],
+            bonus_count: 6,
+            max_actions: 16,
+            filter: PkmFilter::new_allow_all(),
+        }
+    }
+
+    #[test]
+    fn shiny_rolls() {
+        assert_eq!(
+            la_shiny_rolls(LaOutbreakType::MassiveMass, LaResearchLevel::Perfect, true),
+            19
+        );
+        assert_eq!(
+            la_shiny_rolls(LaOutbreakType::Mass, LaResearchLevel::Incomplete, false),
+            26
+        );
+    }
+
+    #[test]
+    fn generates_spawns_within_wave() {
+        let opts = options();
+        let results = la_generate_mmo_paths(&opts);
+
+        assert!(
+            results
+                .iter()
+                .filter(|result| !result.bonus)
+                .all(|result| result.spawn_index < opts.base_count)
+        );
+
+        // The initial spawns don't need any action
+        let initial = results.iter().filter(|result| result.path.is_empty());
+        assert_eq!(initial.count(), MAX_ALIVE);
+
+        results.iter().for_each(|result| {
+            let pokemon = &result.pokemon;
+            assert!((20..=25).contains(&pokemon.level));
+            if pokemon.alpha {
+                let ivs = pokemon.ivs;
+                let perfect = [ivs.hp, ivs.atk, ivs.def, ivs.spa, ivs.spd, ivs.spe]
+                    .into_iter()
+                    .filter(|iv| *iv == 31)
+                    .count();
+                assert!(perfect >= 3);
+            }
+        });
+    }
+
+    #[test]
+    fn bonus_requires_clearing_the_base_wave() {
+        let results = la_generate_mmo_paths(&options());
+
+        results
+            .iter()
+            .filter(|result| result.bonus)
+            .for_each(|result| {
+                assert_eq!(result.pokemon.species, Species::Kleavor);
+                let defeated = result
+                    .path
+                    .iter()
+                    .take_while(|action| **action != MmoAction::StartBonus)
+                    .map(|action| match action {
+                        MmoAction::Defeat { count, .. } => *count as usize,
+                        MmoAction::StartBonus => 0,
+                    })
+                    .sum::<usize>();
+                assert_eq!(defeated, 10);
+            });
+    }
+
+    #[test]
+    fn filters_shinies() {
+        let mut opts = options();
+        opts.filter.shiny = true;
+        let results = la_generate_mmo_paths(&opts);
+
+        assert!(results.iter().all(|result| result.pokemon.shiny));
+    }
+}
@@ -0,0 +1,3 @@
+mod mmo;
+
+pub use mmo::*;
@@ -1,4 +1,6 @@
+pub mod gen1;
 pub mod gen2;
 pub mod gen3;
 pub mod gen4;
 pub mod gen6;
+pub mod gen8;
@@ -0,0 +1,192 @@
+use crate::Console;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+/// GBA_HIT_DISTRIBUTION[index] is the probability that when trying to get advance X, the actual hit frame is X + 4 - index
+/// ex: target is 1000, GBA_HIT_DISTRIBUTION is [4% chance that hit advance is 996, 8%: 997, 12%: 998, 16%: 999, 20%: 1000, 16%: 1001, 12%: 1002, 8%: 1003, 4%:1004]
+pub const GBA_HIT_DISTRIBUTION: [f64; 9] = {
+    let unnormalized_distr: [usize; 9] = [1, 2, 3, 4, 5, 4, 3, 2, 1];
+    let mut normalized_distr: [f64; 9] = [0f64; 9];
+
+    let mut sum: usize = 0;
+    let mut i = 0;
+    loop {
+        sum += unnormalized_distr[i];
+        i += 1;
+        if i >= 9 {
+            break;
+        }
+    }
+
+    let mut i = 0;
+    loop {
+        normalized_distr[i] = unnormalized_distr[i] as f64 / sum as f64;
+        i += 1;
+        if i >= 9 {
+            break;
+        }
+    }
+    normalized_distr
+};
+
+// Players miss by the same amount of time on every console, so faster consoles spread hits over more frames
+const MISS_FRAMES_AT_GBA_FPS: f64 = 5.0;
+
+/// Returns (offset from target, probability) for each advance the player can hit when aiming for a target.
+/// GBA matches GBA_HIT_DISTRIBUTION.
+pub fn hit_distribution(console: Console) -> Vec<(isize, f64)> {
+    let frames_per_gba_frame = console.fps() as f64 / Console::Gba.fps() as f64;
+    let max_offset = (MISS_FRAMES_AT_GBA_FPS * frames_per_gba_frame).ceil() as isize;
+
+    let weights = (-max_offset..=max_offset)
+        .map(|offset| {
+            let gba_frames = offset.unsigned_abs() as f64 / frames_per_gba_frame;
+            (offset, (MISS_FRAMES_AT_GBA_FPS - gba_frames).max(0.0))
+        })
+        .filter(|(_, weight)| *weight > 0.0)
+        .collect::<Vec<(isize, f64)>>();
+    let total = weights.iter().map(|(_, weight)| weight).sum::<f64>();
+
+    weights
+        .into_iter()
+        .map(|(offset, weight)| (offset, weight / total))
+        .collect()
+}
+
+/// The chance of success when aiming for target, given the chance each advance succeeds.
+pub fn hit_probability(
+    console: Console,
+    target: usize,
+    success_chance: impl Fn(usize) -> f64,
+) -> f64 {
+    hit_distribution(console)
+        .into_iter()
+        .filter_map(|(offset, probability)| {
+            let advance = target.checked_add_signed(offset)?;
+            Some(probability * success_chance(advance))
+        })
+        .sum()
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct TargetAdvanceCandidate {
+    pub advance: usize,
+    // 1.0 for a good advance, or lower when the advance only sometimes works
+    pub success_chance: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct TargetHitProbability {
+    pub advance: usize,
+    pub hit_probability: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Tsify, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct TargetHitProbabilityOptions {
+    pub console: Console,
+    pub candidates: Vec<TargetAdvanceCandidate>,
+}
+
+/// Ranks each candidate as a target, easiest to hit first.
+/// Advances missing from the candidates are treated as bad advances.
+#[wasm_bindgen]
+pub fn rank_target_advances(opts: TargetHitProbabilityOptions) -> Vec<TargetHitProbability> {
+    let success_chances = opts
+        .candidates
+        .iter()
+        .map(|candidate| (candidate.advance, candidate.success_chance))
+        .collect::<HashMap<usize, f64>>();
+
+    let mut targets = opts
+        .candidates
+        .iter()
+        .map(|candidate| TargetHitProbability {
+            advance: candidate.advance,
+            hit_probability: hit_probability(opts.console, candidate.advance, |advance| {
+                success_chances.get(&advance).copied().unwrap_or_default()
+            }),
+        })
+        .collect::<Vec<TargetHitProbability>>();
+
+    // Rounded so float error doesn't break ties between equally easy targets
+    let rounded = |target: &TargetHitProbability| (target.hit_probability * 1e9).round();
+    targets.sort_by(|target1, target2| {
+        rounded(target2)
+            .total_cmp(&rounded(target1))
+            .then(target1.advance.cmp(&target2.advance))
+    });
+    targets
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn candidates(advances: &[usize]) -> Vec<TargetAdvanceCandidate> {
+        advances
+            .iter()
+            .map(|&advance| TargetAdvanceCandidate {
+                advance,
+                success_chance: 1.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn gba_matches_distribution_table() {
+        let distribution = hit_distribution(Console::Gba);
+
+        assert_eq!(distribution.len(), GBA_HIT_DISTRIBUTION.len());
+        distribution
+            .iter()
+            .zip(GBA_HIT_DISTRIBUTION)
+            .for_each(|((_, probability), expected)| {
+                assert!((probability - expected).abs() < 1e-12)
+            });
+    }
+
+    #[test]
+    fn distributions_sum_to_one() {
+        for console in [Console::Gba, Console::NdsSlot1, Console::NdsSlot2] {
+            let total = hit_distribution(console)
+                .iter()
+                .map(|(_, probability)| probability)
+                .sum::<f64>();
+            assert!((total - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn ranks_middle_of_good_advances_first() {
+        let targets = rank_target_advances(TargetHitProbabilityOptions {
+            console: Console::Gba,
+            candidates: candidates(&[100, 200, 201, 202]),
+        });
+
+        assert_eq!(
+            targets
+                .iter()
+                .map(|target| target.advance)
+                .collect::<Vec<usize>>(),
+            [201, 200, 202, 100]
+        );
+        assert!((targets[0].hit_probability - 0.52).abs() < 1e-12);
+        assert!((targets[3].hit_probability - 0.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn weighs_partial_successes() {
+        let probability = hit_probability(Console::Gba, 50, |advance| match advance {
+            50 => 0.5,
+            51 => 1.0,
+            _ => 0.0,
+        });
+
+        assert!((probability - (0.2 * 0.5 + 0.16)).abs() < 1e-12);
+    }
+}
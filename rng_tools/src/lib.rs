@@ -1,4 +1,5 @@
 mod generators;
+mod hit_probability;
 mod id;
 mod ivs;
 mod pkm;
@@ -8,6 +9,7 @@ mod time;
 mod timer;
 
 pub use generators::*;
+pub use hit_probability::*;
 pub use id::*;
 pub use ivs::*;
 pub use pkm::*;
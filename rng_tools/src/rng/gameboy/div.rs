@@ -16,7 +16,17 @@ fn div_increment(index: usize) -> u8 {
     }
 }
 
-#[derive(Debug, Clone)]
+// Indexes with the same pattern increment the same way for the next `horizon` advances
+fn index_pattern(index: usize, horizon: usize) -> (usize, Vec<usize>) {
+    let adjusted_offsets = ADJUSTED_INDEXES
+        .iter()
+        .map(|&adjusted| (adjusted + DIV_CYCLE_LENGTH - index) % DIV_CYCLE_LENGTH)
+        .filter(|&offset| offset < horizon)
+        .collect::<Vec<_>>();
+    (index % DIV_INCREMENTS.len(), adjusted_offsets)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Div {
     index: usize,
     value: u8,
@@ -56,4 +66,52 @@ impl Div {
     pub fn decrement_index(&mut self, value: usize) {
         self.index = self.index.wrapping_sub(value) % DIV_CYCLE_LENGTH;
     }
+
+    /// One index for every way DIV can increment over the next `horizon` advances.
+    pub fn candidate_indexes(horizon: usize) -> Vec<usize> {
+        let mut patterns = vec![];
+        let mut indexes = vec![];
+        for index in 0..DIV_CYCLE_LENGTH {
+            let pattern = index_pattern(index, horizon);
+            if !patterns.contains(&pattern) {
+                patterns.push(pattern);
+                indexes.push(index);
+            }
+        }
+
+        indexes
+    }
+
+    /// Finds every DIV whose next values are `observed`.
+    /// Indexes that behave the same for the next `horizon` advances are only returned once,
+    /// since they only differ once an adjusted index is reached.
+    pub fn solve(observed: &[u8], horizon: usize) -> Vec<Self> {
+        let Some(&first) = observed.first() else {
+            return vec![];
+        };
+
+        let mut patterns = vec![];
+        let mut result = vec![];
+        for index in 0..DIV_CYCLE_LENGTH {
+            let value = first.wrapping_sub(div_increment(index));
+            let mut div = Div::new(index, value);
+            let matches = observed.iter().all(|&expected| {
+                div.next();
+                div.value() == expected
+            });
+
+            if !matches {
+                continue;
+            }
+
+            let pattern = (value, index_pattern(index, horizon));
+
+            if !patterns.contains(&pattern) {
+                patterns.push(pattern);
+                result.push(Div::new(index, value));
+            }
+        }
+
+        result
+    }
 }
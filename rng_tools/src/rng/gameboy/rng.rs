@@ -108,6 +108,54 @@ impl GameboyRng {
     pub fn sdiv(&self) -> u8 {
         self.sub_div.value()
     }
+
+    /// Recovers the DIV values used between each of the consecutive observed rand states.
+    pub fn observed_divs(states: &[u16]) -> Vec<[u8; 2]> {
+        states
+            .windows(2)
+            .map(|window| {
+                let [r_add, r_sub] = window[0].to_be_bytes();
+                let [next_r_add, next_r_sub] = window[1].to_be_bytes();
+                let a_div = next_r_add.wrapping_sub(r_add);
+                let add_overload = r_add.checked_add(a_div).is_none();
+                let s_div = r_sub
+                    .wrapping_sub(next_r_sub)
+                    .wrapping_sub(add_overload as u8);
+                debug_assert_eq!(
+                    Self::advance_state(r_add, r_sub, a_div, s_div),
+                    [next_r_add, next_r_sub]
+                );
+                [a_div, s_div]
+            })
+            .collect()
+    }
+
+    /// Finds every rng that starts at the first observed state and produces the following ones.
+    pub fn solve(states: &[u16], horizon: usize) -> Vec<Self> {
+        let Some(&state) = states.first() else {
+            return vec![];
+        };
+
+        let divs = Self::observed_divs(states);
+        let horizon = horizon.max(divs.len());
+        let add_divs = Div::solve(
+            &divs.iter().map(|[a_div, _]| *a_div).collect::<Vec<_>>(),
+            horizon,
+        );
+        let sub_divs = Div::solve(
+            &divs.iter().map(|[_, s_div]| *s_div).collect::<Vec<_>>(),
+            horizon,
+        );
+
+        add_divs
+            .iter()
+            .flat_map(|add_div| {
+                sub_divs
+                    .iter()
+                    .map(move |sub_div| Self::new(state, add_div.clone(), sub_div.clone()))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -137,4 +185,33 @@ mod test {
 
         assert_list_eq!(results, expected);
     }
+
+    #[test]
+    fn solves_divs_from_rands() {
+        let adiv = Div::new(468, 0x78);
+        let sdiv = Div::new(16139, 0x78);
+        let mut rng = GameboyRng::new(0x9fe3, adiv.clone(), sdiv.clone());
+        let states = [0x9fe3]
+            .into_iter()
+            .chain((0..40).map(|_| rng.next_u16()))
+            .collect::<Vec<_>>();
+
+        let solutions = GameboyRng::solve(&states, 100);
+        assert!(!solutions.is_empty());
+        let mut expected_rng = GameboyRng::new(0x9fe3, adiv, sdiv);
+        let expected = (0..100)
+            .map(|_| expected_rng.next_u16())
+            .collect::<Vec<_>>();
+
+        let solution_rands = solutions
+            .into_iter()
+            .map(|mut solution| (0..100).map(|_| solution.next_u16()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert!(
+            solution_rands
+                .iter()
+                .all(|rands| rands[..40] == states[1..])
+        );
+        assert!(solution_rands.contains(&expected));
+    }
 }
@@ -3,6 +3,8 @@ use std::iter::{DoubleEndedIterator, Iterator, Rev, Skip};
 
 pub type Pokerng = Lcrng<0x6073, 0x41c64e6d, 0xa3561a1, 0xeeb9eb65>;
 pub type Xdrng = Lcrng<0x269EC3, 0x343FD, 0xA170F641, 0xB9B33155>;
+// Gen 3 uses this one to place Feebas on Route 119
+pub type Feebasrng = Lcrng<0x3039, 0x41c64e6d, 0xfc77a683, 0xeeb9eb65>;
 
 #[derive(Debug, Clone, Copy)]
 pub struct Lcrng<const ADD: u32, const MUL: u32, const P_ADD: u32, const P_MUL: u32> {
@@ -15,6 +17,12 @@ impl Pokerng {
     }
 }
 
+impl Xdrng {
+    pub fn seed(&self) -> u32 {
+        self.state
+    }
+}
+
 impl<const A: u32, const M: u32, const PA: u32, const PM: u32> Lcrng<A, M, PA, PM> {
     pub fn new(seed: u32) -> Self {
         Self { state: seed }
@@ -33,6 +41,26 @@ impl<const A: u32, const M: u32, const PA: u32, const PM: u32> Lcrng<A, M, PA, P
     fn next_u16(&mut self) -> u16 {
         (self.next_state() >> 16) as u16
     }
+
+    // Jumps by powers of two, fixing one bit of the state at a time
+    pub fn distance_to(&self, target: u32) -> u32 {
+        let mut state = self.state;
+        let mut mul = M;
+        let mut add = A;
+        let mut distance = 0u32;
+
+        for bit in 0..32 {
+            let mask = 1u32 << bit;
+            if (state ^ target) & mask != 0 {
+                state = state.wrapping_mul(mul).wrapping_add(add);
+                distance |= mask;
+            }
+            add = add.wrapping_mul(mul).wrapping_add(add);
+            mul = mul.wrapping_mul(mul);
+        }
+
+        distance
+    }
 }
 
 impl<const A: u32, const M: u32, const PA: u32, const PM: u32> Iterator for Lcrng<A, M, PA, PM> {
@@ -136,6 +164,17 @@ mod test {
     use super::*;
     use crate::assert_list_eq;
 
+    #[test]
+    fn distance_to() {
+        let mut rng = Pokerng::new(0x5a0);
+        rng.advance(123_456);
+        let target = rng.rand::<u32>();
+
+        assert_eq!(Pokerng::new(0x5a0).distance_to(target), 123_457);
+        assert_eq!(Pokerng::new(target).distance_to(target), 0);
+        assert_eq!(Xdrng::new(0).distance_to(Xdrng::new(0).rand::<u32>()), 1);
+    }
+
     #[test]
     fn produces_correct_rands() {
         let expected: [u32; 1000] = [
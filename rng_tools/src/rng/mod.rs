@@ -4,6 +4,7 @@ pub mod mt;
 mod rng_trait;
 mod state_iter;
 pub mod tinymt;
+pub mod xoroshiro;
 
 pub use rng_trait::*;
 pub use state_iter::*;
@@ -0,0 +1,129 @@
+use super::Rng;
+use super::{GetMaxRand, GetRand};
+
+const XOROSHIRO_CONST: u64 = 0x82a2b175229d6a5b;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Xoroshiro {
+    state: [u64; 2],
+}
+
+impl Xoroshiro {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: [seed, XOROSHIRO_CONST],
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let [s0, mut s1] = self.state;
+        let result = s0.wrapping_add(s1);
+
+        s1 ^= s0;
+        self.state = [s0.rotate_left(24) ^ s1 ^ (s1 << 16), s1.rotate_left(37)];
+
+        result
+    }
+
+    // The games reroll until the masked value is below max instead of using a modulo
+    fn next_masked(&mut self, max: u64) -> u64 {
+        // Nothing is below zero, so there's nothing to reroll for
+        if max == 0 {
+            return 0;
+        }
+
+        let mask = u64::MAX
+            .checked_shr(max.wrapping_sub(1).leading_zeros())
+            .unwrap_or_default();
+
+        loop {
+            let rand = self.next_u64() & mask;
+            if rand < max {
+                return rand;
+            }
+        }
+    }
+
+    pub fn next_float(&mut self, range: f32) -> f32 {
+        ((self.next_u64() >> 40) as f32) * (1.0 / 16777216.0) * range
+    }
+}
+
+impl Iterator for Xoroshiro {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_u64())
+    }
+}
+
+impl GetRand<u32> for Xoroshiro {
+    fn get(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+}
+
+impl GetRand<u64> for Xoroshiro {
+    fn get(&mut self) -> u64 {
+        self.next_u64()
+    }
+}
+
+impl GetMaxRand<u32> for Xoroshiro {
+    fn get_max(&mut self, max: u32) -> u32 {
+        self.next_masked(max as u64) as u32
+    }
+}
+
+impl GetMaxRand<u64> for Xoroshiro {
+    fn get_max(&mut self, max: u64) -> u64 {
+        self.next_masked(max)
+    }
+}
+
+impl Rng for Xoroshiro {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assert_list_eq;
+
+    #[test]
+    fn produces_correct_rands() {
+        let expected = [
+            0x94d707edb349384a,
+            0x55e0f5890d6d7598,
+            0xc0cfbb44b3f88647,
+            0xbe5415b5765d7a8f,
+            0xe6cc3dd79a1b00a9,
+            0x0ee407599d3ca4bd,
+            0x5d49ba06fcc9ca7d,
+            0xf2552665ad3cbfca,
+            0x71d899bd43aced58,
+            0x8db072cab461e15b,
+        ];
+        let results = Xoroshiro::new(0x1234567890abcdef)
+            .take(10)
+            .collect::<Vec<u64>>();
+
+        assert_list_eq!(results, expected);
+    }
+
+    #[test]
+    fn rand_max_uses_bitmask() {
+        let mut rng = Xoroshiro::new(0x1234567890abcdef);
+
+        // 0x4a & 0x1f = 10, 0x98 & 0x1f = 24
+        assert_eq!(rng.rand_max::<u32>(25), 10);
+        assert_eq!(rng.rand_max::<u32>(25), 24);
+        assert_eq!(rng.rand_max::<u32>(1), 0);
+    }
+
+    #[test]
+    fn rand_max_zero_returns_zero() {
+        let mut rng = Xoroshiro::new(0x1234567890abcdef);
+
+        assert_eq!(rng.rand_max::<u64>(0), 0);
+        assert_eq!(rng.rand::<u64>(), 0x94d707edb349384a);
+    }
+}
@@ -11,6 +11,7 @@ mod min_time;
 mod minutes_before;
 mod second_timer;
 
+pub use console::*;
 pub use gen3_timer::*;
 pub use gen4_timer::*;
 pub use gen5_timer::*;
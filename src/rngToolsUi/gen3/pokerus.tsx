@@ -12,7 +12,11 @@ import {
   FormFieldTable,
   FormikNumberInput,
 } from "~/components";
-import { rngTools, Pokerus3GeneratorResult } from "~/rngTools";
+import {
+  rngTools,
+  Pokerus3GeneratorResult,
+  Pokerus3VersionOptions,
+} from "~/rngTools";
 import { FormikRadio } from "~/components/radio";
 import React from "react";
 import { z } from "zod";
@@ -20,7 +24,15 @@ import { toOptions } from "~/utils/options";
 import { range } from "lodash-es";
 import { useFormikContext } from "formik";
 import { match, P } from "ts-pattern";
-import { pickupIdToName, pickupItems } from "~/types/pickupItems";
+import {
+  emeraldPickupItems,
+  frlgPickupItems,
+  pickupIdToName,
+  pickupItems,
+  rsPickupItems,
+  toPickupItem,
+} from "~/types/pickupItems";
+import { HexSchema } from "~/utils/number";
 
 const HAS_EMPTY_TV_NEWS_SLOT = true; // The tool assumes the player always has a empty TV News slot.
 const LEVEL_UP = false; // The tool assumes the player's Pokémon won't level-up after the battle.
@@ -34,7 +46,13 @@ type Column = Pokerus3GeneratorResult & {
 
 const pickupItemSchema = z.enum(pickupItems);
 
+const games = ["RS Dead Battery", "RS Seed", "Emerald", "FRLG"] as const;
+type Game = (typeof games)[number];
+
 const Validator = z.object({
+  game: z.enum(games),
+  seed: HexSchema(0xffff),
+  pickup_level: z.number().int().min(1).max(100),
   entered_hall_of_fame: z.boolean(),
   had_mass_outbreak: z.boolean(),
   pickup_pokemon_count: z.number().int().min(0).max(5),
@@ -52,6 +70,9 @@ const Validator = z.object({
 export type FormState = z.infer<typeof Validator>;
 
 const initialValues: FormState = {
+  game: "RS Dead Battery",
+  seed: 0,
+  pickup_level: 1,
   entered_hall_of_fame: false,
   had_mass_outbreak: false,
   pickup_pokemon_count: 5,
@@ -66,6 +87,21 @@ const initialValues: FormState = {
   targetAdv: 26838,
 };
 
+const getVersionOptions = (values: FormState): Pokerus3VersionOptions =>
+  match(values.game)
+    .with("RS Dead Battery", () => ({ Rs: "DeadBattery" as const }))
+    .with("RS Seed", () => ({ Rs: { Seed: values.seed } }))
+    .with("Emerald", () => "Emerald" as const)
+    .with("FRLG", () => ({ Frlg: values.seed }))
+    .exhaustive();
+
+const getPickupItemOptions = (game: Game) =>
+  match(game)
+    .with("RS Dead Battery", "RS Seed", () => rsPickupItems)
+    .with("Emerald", () => emeraldPickupItems)
+    .with("FRLG", () => frlgPickupItems)
+    .exhaustive();
+
 const getTargetAdvanceBeforePickup = async (values: {
   entered_hall_of_fame: boolean;
   had_mass_outbreak: boolean;
@@ -89,6 +125,7 @@ const generateResults = async (values: FormState): Promise<Column[]> => {
     await getTargetAdvanceBeforePickup(values);
 
   const opts = {
+    version_options: getVersionOptions(values),
     initial_advance_before_pickup: values.filter_active
       ? 0
       : values.initial_advance_before_pickup,
@@ -96,7 +133,9 @@ const generateResults = async (values: FormState): Promise<Column[]> => {
     entered_hall_of_fame: values.entered_hall_of_fame,
     can_have_new_mass_outbreak: !values.had_mass_outbreak,
     has_empty_pokenews_slot: HAS_EMPTY_TV_NEWS_SLOT,
-    pickup_pokemon_count: values.pickup_pokemon_count,
+    pickup_levels: range(values.pickup_pokemon_count).map(
+      () => values.pickup_level,
+    ),
     level_up: LEVEL_UP,
     filter_pickup_items: values.filter_active
       ? [
@@ -105,7 +144,9 @@ const generateResults = async (values: FormState): Promise<Column[]> => {
           values.filter_pickup_items_2,
           values.filter_pickup_items_3,
           values.filter_pickup_items_4,
-        ].slice(0, values.pickup_pokemon_count)
+        ]
+          .slice(0, values.pickup_pokemon_count)
+          .map(toPickupItem)
       : null,
     filter_gives_pokerus: null,
   };
@@ -200,6 +241,35 @@ export const Fields = () => {
 
   const fields = React.useMemo((): Field[] => {
     const fields: Field[] = [
+      {
+        label: "Game",
+        input: (
+          <FormikSelect<FormState, "game">
+            name="game"
+            options={games.map((game) => ({ label: game, value: game }))}
+          />
+        ),
+      },
+    ];
+
+    if (values.game === "RS Seed" || values.game === "FRLG") {
+      fields.push({
+        label: "Seed",
+        input: <FormikNumberInput<FormState> name="seed" numType="hex" />,
+      });
+    }
+
+    // Only Emerald's items depend on the level
+    if (values.game === "Emerald") {
+      fields.push({
+        label: "Pickup Level",
+        input: (
+          <FormikNumberInput<FormState> name="pickup_level" numType="decimal" />
+        ),
+      });
+    }
+
+    fields.push(
       {
         label: "Entered Hall of Fame",
         input: (
@@ -209,7 +279,7 @@ export const Fields = () => {
           > name="entered_hall_of_fame" />
         ),
       },
-    ];
+    );
 
     if (values.entered_hall_of_fame) {
       fields.push({
@@ -279,7 +349,10 @@ export const Fields = () => {
     }
 
     if (values.filter_active) {
-      const itemOptions = pickupItems.map((id) => ({
+      const itemOptions = [
+        "None" as const,
+        ...getPickupItemOptions(values.game),
+      ].map((id) => ({
         label: pickupIdToName(id),
         value: id,
       }));
@@ -323,6 +396,7 @@ export const Fields = () => {
     }
     return fields;
   }, [
+    values.game,
     values.entered_hall_of_fame,
     values.filter_active,
     values.pickup_pokemon_count,
@@ -391,7 +465,7 @@ export const Gen3Pokerus = () => {
               : POKERUS_TARGETS[0];
 
           const pokerusAdv = formatAdvDiff(
-            values.advance_before_pokerus,
+            values.outcomes[0].advance_before_pokerus,
             pokerusTarget,
           );
 
@@ -400,7 +474,7 @@ export const Gen3Pokerus = () => {
       },
       {
         title: "",
-        dataIndex: "advance_before_pokerus",
+        dataIndex: "outcomes",
         render: (_val, values) => {
           return (
             <UpdateCalibrationBtn
@@ -417,7 +491,7 @@ export const Gen3Pokerus = () => {
         render: (val) => {
           const items = val
             .map((itemId, pokemonSlot) => {
-              if (itemId === "None") {
+              if (itemId == null) {
                 return null;
               }
               return `${pokemonSlot + 1}: ${pickupIdToName(itemId)}`;
@@ -1,6 +1,11 @@
-import { rngTools, Static3SearcherResult } from "~/rngTools";
+import {
+  rngTools,
+  Static3InitialSeeds,
+  Static3SearcherResult,
+} from "~/rngTools";
 import {
   Field,
+  FormikInput,
   FormikNumberInput,
   FormikSelect,
   FormikSwitch,
@@ -22,6 +27,8 @@ import {
 } from "~/rngToolsUi/shared/ivColumns";
 import { z } from "zod";
 import { species } from "~/types/species";
+import { match } from "ts-pattern";
+import { HexSchema } from "~/utils/number";
 
 type Result = FlattenIvs<Static3SearcherResult>;
 
@@ -47,8 +54,24 @@ const columns: ResultColumn<Result>[] = [
     render: (shiny) => (shiny ? "Yes" : "No"),
   },
   { title: "Gender", dataIndex: "gender" },
+  {
+    title: "Initial Seed",
+    dataIndex: "initial_seed",
+    monospace: true,
+    render: (initialSeed) =>
+      initialSeed == null
+        ? "-"
+        : initialSeed.toString(16).padStart(4, "0").toUpperCase(),
+  },
+  {
+    title: "Advance",
+    dataIndex: "advance",
+    render: (advance) => (advance == null ? "-" : advance),
+  },
 ];
 
+const rsSeedTypes = ["Dead Battery", "Live Battery", "Seed"] as const;
+
 const Validator = z
   .object({
     tid: z.number().int().min(0).max(65535),
@@ -56,6 +79,16 @@ const Validator = z
     species: z.enum(species),
     roamer: z.boolean(),
     method4: z.boolean(),
+    trace_initial_seeds: z.boolean(),
+    initial_seed_max_advances: z.number().int().min(0),
+    rs_seed_type: z.enum(rsSeedTypes),
+    rs_seed: HexSchema(0xffff),
+    frlg_seeds: z
+      .string()
+      .regex(
+        /^\s*[0-9a-fA-F]{1,4}(\s*,\s*[0-9a-fA-F]{1,4})*\s*$/,
+        "Must be comma separated hex seeds",
+      ),
   })
   .merge(pkmFilterSchema);
 
@@ -68,6 +101,11 @@ const getInitialValues = (game: Static3Game): FormState => {
     species: getStatic3Species(game)[0],
     roamer: false,
     method4: false,
+    trace_initial_seeds: false,
+    initial_seed_max_advances: 100_000,
+    rs_seed_type: "Dead Battery",
+    rs_seed: 0,
+    frlg_seeds: "0",
     filter_shiny: false,
     filter_min_ivs: minIvs,
     filter_max_ivs: maxIvs,
@@ -77,6 +115,50 @@ const getInitialValues = (game: Static3Game): FormState => {
   };
 };
 
+const getInitialSeedFields = (game: Static3Game): Field[] =>
+  match(game)
+    .with("emerald", (): Field[] => [])
+    .with("rs", (): Field[] => [
+      {
+        label: "Initial Seed Type",
+        input: (
+          <FormikSelect<FormState, "rs_seed_type">
+            name="rs_seed_type"
+            options={rsSeedTypes.map((ty) => ({ label: ty, value: ty }))}
+          />
+        ),
+      },
+      {
+        label: "Initial Seed",
+        input: <FormikNumberInput<FormState> name="rs_seed" numType="hex" />,
+      },
+    ])
+    .with("frlg", (): Field[] => [
+      {
+        label: "Initial Seeds",
+        input: <FormikInput<FormState> name="frlg_seeds" />,
+      },
+    ])
+    .exhaustive();
+
+const getInitialSeeds = (
+  game: Static3Game,
+  opts: FormState,
+): Static3InitialSeeds =>
+  match(game)
+    .with("emerald", () => "Emerald" as const)
+    .with("rs", () =>
+      match(opts.rs_seed_type)
+        .with("Dead Battery", () => ({ Rs: "DeadBattery" as const }))
+        .with("Live Battery", () => "RsLiveBattery" as const)
+        .with("Seed", () => ({ Rs: { Seed: opts.rs_seed } }))
+        .exhaustive(),
+    )
+    .with("frlg", () => ({
+      Frlg: opts.frlg_seeds.split(",").map((seed) => parseInt(seed, 16)),
+    }))
+    .exhaustive();
+
 const getFields = (game: Static3Game): Field[] => {
   const staticSpecies = getStatic3Species(game);
   return [
@@ -107,6 +189,24 @@ const getFields = (game: Static3Game): Field[] => {
       label: "Method 4",
       input: <FormikSwitch<FormState, "method4"> name="method4" />,
     },
+    {
+      label: "Search From Initial Seeds",
+      input: (
+        <FormikSwitch<FormState, "trace_initial_seeds">
+          name="trace_initial_seeds"
+        />
+      ),
+    },
+    {
+      label: "Initial Seed Max Advances",
+      input: (
+        <FormikNumberInput<FormState>
+          name="initial_seed_max_advances"
+          numType="decimal"
+        />
+      ),
+    },
+    ...getInitialSeedFields(game),
     ...getPkmFilterFields(),
   ];
 };
@@ -134,6 +234,12 @@ export const Static3Searcher = ({ game }: Props) => {
           max_ivs: opts.filter_max_ivs,
           stats: null,
         },
+        initial_seeds: opts.trace_initial_seeds
+          ? {
+              seeds: getInitialSeeds(game, opts),
+              max_advances: opts.initial_seed_max_advances,
+            }
+          : null,
       });
 
       setResults(results.map(flattenIvs));
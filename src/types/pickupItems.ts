@@ -1,8 +1,7 @@
-import { PickUpItem } from "~/rngTools";
+import { Gen3PickupItem } from "~/rngTools";
 import { match } from "ts-pattern";
 
-export const pickupItems = [
-  "None",
+export const rsPickupItems = [
   "SuperPotion",
   "FullHeal",
   "UltraBall",
@@ -13,20 +12,148 @@ export const pickupItems = [
   "Protein",
   "PpUp",
   "KingsRock",
-] as const satisfies PickUpItem[];
+] as const satisfies Gen3PickupItem[];
+
+export const emeraldPickupItems = [
+  "Potion",
+  "Antidote",
+  "SuperPotion",
+  "GreatBall",
+  "Repel",
+  "EscapeRope",
+  "XAttack",
+  "FullHeal",
+  "UltraBall",
+  "HyperPotion",
+  "RareCandy",
+  "Protein",
+  "Revive",
+  "HpUp",
+  "FullRestore",
+  "MaxRevive",
+  "PpUp",
+  "MaxElixir",
+  "Nugget",
+  "KingsRock",
+  "Ether",
+  "WhiteHerb",
+  "Tm44Rest",
+  "Elixir",
+  "Tm01FocusPunch",
+  "Leftovers",
+  "Tm26Earthquake",
+] as const satisfies Gen3PickupItem[];
+
+export const frlgPickupItems = [
+  "OranBerry",
+  "CheriBerry",
+  "ChestoBerry",
+  "PechaBerry",
+  "RawstBerry",
+  "AspearBerry",
+  "PersimBerry",
+  "Tm10HiddenPower",
+  "PpUp",
+  "RareCandy",
+  "Nugget",
+  "SpelonBerry",
+  "PamtreBerry",
+  "WatmelBerry",
+  "DurinBerry",
+  "BelueBerry",
+] as const satisfies Gen3PickupItem[];
+
+export const pickupItems = [
+  "None",
+  "Potion",
+  "Antidote",
+  "SuperPotion",
+  "GreatBall",
+  "Repel",
+  "EscapeRope",
+  "XAttack",
+  "FullHeal",
+  "UltraBall",
+  "HyperPotion",
+  "RareCandy",
+  "Protein",
+  "Revive",
+  "HpUp",
+  "FullRestore",
+  "MaxRevive",
+  "PpUp",
+  "MaxElixir",
+  "Nugget",
+  "KingsRock",
+  "Ether",
+  "WhiteHerb",
+  "Tm44Rest",
+  "Elixir",
+  "Tm01FocusPunch",
+  "Leftovers",
+  "Tm26Earthquake",
+  "OranBerry",
+  "CheriBerry",
+  "ChestoBerry",
+  "PechaBerry",
+  "RawstBerry",
+  "AspearBerry",
+  "PersimBerry",
+  "Tm10HiddenPower",
+  "SpelonBerry",
+  "PamtreBerry",
+  "WatmelBerry",
+  "DurinBerry",
+  "BelueBerry",
+] as const satisfies (Gen3PickupItem | "None")[];
+
+export type PickupItemOption = (typeof pickupItems)[number];
+
+export const toPickupItem = (id: PickupItemOption): Gen3PickupItem | null =>
+  id === "None" ? null : id;
 
-export const pickupIdToName = (id: PickUpItem) => {
+export const pickupIdToName = (id: Gen3PickupItem | "None") => {
   return match(id)
     .with("None", () => "None")
+    .with("Potion", () => "Potion")
+    .with("Antidote", () => "Antidote")
     .with("SuperPotion", () => "Super Potion")
+    .with("GreatBall", () => "Great Ball")
+    .with("Repel", () => "Repel")
+    .with("EscapeRope", () => "Escape Rope")
+    .with("XAttack", () => "X Attack")
     .with("FullHeal", () => "Full Heal")
     .with("UltraBall", () => "Ultra Ball")
+    .with("HyperPotion", () => "Hyper Potion")
     .with("RareCandy", () => "Rare Candy")
-    .with("FullRestore", () => "Full Restore")
-    .with("Revive", () => "Revive")
-    .with("Nugget", () => "Nugget")
     .with("Protein", () => "Protein")
+    .with("Revive", () => "Revive")
+    .with("HpUp", () => "HP Up")
+    .with("FullRestore", () => "Full Restore")
+    .with("MaxRevive", () => "Max Revive")
     .with("PpUp", () => "PP Up")
+    .with("MaxElixir", () => "Max Elixir")
+    .with("Nugget", () => "Nugget")
     .with("KingsRock", () => "King's Rock")
-    .otherwise(() => "Unknown Item");
+    .with("Ether", () => "Ether")
+    .with("WhiteHerb", () => "White Herb")
+    .with("Tm44Rest", () => "TM44 Rest")
+    .with("Elixir", () => "Elixir")
+    .with("Tm01FocusPunch", () => "TM01 Focus Punch")
+    .with("Leftovers", () => "Leftovers")
+    .with("Tm26Earthquake", () => "TM26 Earthquake")
+    .with("OranBerry", () => "Oran Berry")
+    .with("CheriBerry", () => "Cheri Berry")
+    .with("ChestoBerry", () => "Chesto Berry")
+    .with("PechaBerry", () => "Pecha Berry")
+    .with("RawstBerry", () => "Rawst Berry")
+    .with("AspearBerry", () => "Aspear Berry")
+    .with("PersimBerry", () => "Persim Berry")
+    .with("Tm10HiddenPower", () => "TM10 Hidden Power")
+    .with("SpelonBerry", () => "Spelon Berry")
+    .with("PamtreBerry", () => "Pamtre Berry")
+    .with("WatmelBerry", () => "Watmel Berry")
+    .with("DurinBerry", () => "Durin Berry")
+    .with("BelueBerry", () => "Belue Berry")
+    .exhaustive();
 };